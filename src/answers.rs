@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::solutions::answer::Answer;
+
+// Accepted answers of a year, stored as `DAY PART ANSWER` lines in
+// `YEAR/answers`, or `YEAR/answers.NAME` for the inputs named NAME. Newlines
+// and backslashes in answers are escaped.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+pub fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                chars.next();
+                res.push('\n');
+            }
+            ('\\', Some('\\')) => {
+                chars.next();
+                res.push('\\');
+            }
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+impl AnswerStore {
+    pub fn location(year: u16, input: Option<&str>) -> PathBuf {
+        match input {
+            None => PathBuf::from(format!("{}/answers", year)),
+            Some(name) => PathBuf::from(format!("{}/answers.{}", year, name)),
+        }
+    }
+
+    pub fn load(year: u16, input: Option<&str>) -> std::io::Result<Self> {
+        let path = Self::location(year, input);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (i, l) in content.lines().enumerate() {
+            if l.trim().is_empty() || l.starts_with('#') {
+                continue;
+            }
+            let mut it = l.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (it.next(), it.next(), it.next()) else {
+                return Err(format!("line {}: expected `DAY PART ANSWER`", i + 1));
+            };
+            let day = day
+                .parse()
+                .map_err(|_| format!("line {}: invalid day {}", i + 1, day))?;
+            let part = part
+                .parse()
+                .map_err(|_| format!("line {}: invalid part {}", i + 1, part))?;
+            answers.insert((day, part), Answer::parse(&unescape(answer)));
+        }
+        Ok(AnswerStore { answers })
+    }
+
+    pub fn save(&self, year: u16, input: Option<&str>) -> std::io::Result<()> {
+        std::fs::create_dir_all(year.to_string())?;
+        std::fs::write(Self::location(year, input), self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Mismatch(expected.clone()),
+        }
+    }
+}
+
+impl Display for AnswerStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), answer) in self.answers.iter() {
+            writeln!(f, "{} {} {}", day, part, escape(&answer.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Mismatch(Answer),
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{AnswerStore, Verdict};
+    use crate::solutions::answer::Answer;
+
+    #[test]
+    fn round_trip() {
+        let mut store = AnswerStore::default();
+        store.set(1, 1, Answer::Integer(1301));
+        store.set(10, 2, Answer::Grid(vec!["#..#".into(), "\\..#".into()]));
+        store.set(2, 1, Answer::Text("two words".into()));
+
+        let content = store.to_string();
+        assert_eq!(content, "1 1 1301\n2 1 two words\n10 2 #..#\\n\\\\..#\n");
+        assert_eq!(AnswerStore::parse(&content).unwrap(), store);
+    }
+
+    #[test]
+    fn check() {
+        let store = AnswerStore::parse("# comment\n1 1 42\n\n1 2 7\n").unwrap();
+        assert_eq!(store.check(1, 1, &Answer::Integer(42)), Verdict::Correct);
+        assert_eq!(
+            store.check(1, 2, &Answer::Integer(8)),
+            Verdict::Mismatch(Answer::Integer(7))
+        );
+        assert_eq!(store.check(2, 1, &Answer::Integer(8)), Verdict::Unknown);
+        assert!(AnswerStore::parse("1 x 42").is_err());
+        assert!(AnswerStore::parse("1 1").is_err());
+    }
+
+    #[test]
+    fn location() {
+        assert_eq!(
+            AnswerStore::location(2023, None),
+            PathBuf::from("2023/answers")
+        );
+        assert_eq!(
+            AnswerStore::location(2023, Some("alice")),
+            PathBuf::from("2023/answers.alice")
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::time::Duration;
+
+use regex::Regex;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub fn get_session() -> Result<String, Box<dyn Error>> {
+    let home = dirs::home_dir().ok_or("cannot find home directory")?;
+    let session_file = home.join(".aoc");
+    std::fs::read_to_string(session_file)
+        .map(|s| s.trim().to_owned())
+        .map_err(From::from)
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    client: reqwest::Client,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: String) -> Result<Self, Box<dyn Error>> {
+        Ok(AocClient {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            client: reqwest::Client::builder().build()?,
+        })
+    }
+
+    async fn get(&self, path: &str) -> Result<String, Box<dyn Error>> {
+        let res = self
+            .client
+            .get(format!("{}{}", self.base_url, path))
+            .header("cookie", format!("session={}", self.session))
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(res)
+    }
+
+    pub async fn input(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        self.get(&format!("/{}/day/{}/input", year, day)).await
+    }
+
+    pub async fn puzzle(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        self.get(&format!("/{}/day/{}", year, day)).await
+    }
+
+    pub async fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, Box<dyn Error>> {
+        let res = self
+            .client
+            .post(format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .header("cookie", format!("session={}", self.session))
+            .form(&[("level", part.to_string()), ("answer", answer.to_owned())])
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(Outcome::parse(&res))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+    Unknown(String),
+}
+
+fn article_text(html: &str) -> String {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let content = article
+        .captures(html)
+        .map(|c| c.get(1).unwrap().as_str())
+        .unwrap_or(html);
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    let text = tags.replace_all(content, "");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_wait(text: &str) -> Option<Duration> {
+    let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let c = wait.captures(text)?;
+    let minutes: u64 = c.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = c.get(2)?.as_str().parse().ok()?;
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+impl Outcome {
+    pub fn parse(html: &str) -> Outcome {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited(parse_wait(&text))
+        } else if text.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::RateLimited(Some(d)) => {
+                write!(f, "rate limited, wait {}s", d.as_secs())
+            }
+            Outcome::RateLimited(None) => write!(f, "rate limited"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use super::{AocClient, Outcome};
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn parse() {
+        let correct = page("That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(Outcome::parse(&correct), Outcome::Correct);
+
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>");
+        assert_eq!(Outcome::parse(&high), Outcome::TooHigh);
+
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(Outcome::parse(&low), Outcome::TooLow);
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(Outcome::parse(&wrong), Outcome::Wrong);
+
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        assert_eq!(
+            Outcome::parse(&limited),
+            Outcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.");
+        assert_eq!(
+            Outcome::parse(&limited),
+            Outcome::RateLimited(Some(Duration::from_secs(37)))
+        );
+
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Outcome::parse(&solved), Outcome::AlreadySolved);
+
+        assert!(matches!(
+            Outcome::parse("<html></html>"),
+            Outcome::Unknown(_)
+        ));
+    }
+
+    // Answers a single request with `body` and returns the raw request.
+    fn stub_server(body: String) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 4096];
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, content)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|l| {
+                            l.to_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(|v| v.parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if content.len() >= length {
+                        break;
+                    }
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn submit() {
+        let (url, server) = stub_server(page("That's the right answer!"));
+        let client = AocClient::new(&url, "abc".into()).unwrap();
+        let outcome = client.submit(2023, 5, 2, "42").await.unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.contains("cookie: session=abc"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+}
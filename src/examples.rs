@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use crate::answers::{escape, unescape};
+use crate::puzzle::Example;
+use crate::solutions::registry::Day;
+use crate::summary::solve_catching;
+
+// Examples of a day are stored as `YEAR/DAY/example_N` input files, with the
+// expected answers in `YEAR/DAY/examples` as `N PART ANSWER` lines.
+pub fn example_location(year: u16, day: u8, n: usize) -> PathBuf {
+    PathBuf::from(format!("{}/{}/example_{}", year, day, n))
+}
+
+pub fn expected_location(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("{}/{}/examples", year, day))
+}
+
+pub fn save(year: u16, day: u8, examples: &[Example]) -> std::io::Result<()> {
+    std::fs::create_dir_all(format!("{}/{}", year, day))?;
+    let mut expected = String::new();
+    for (i, e) in examples.iter().enumerate() {
+        std::fs::write(example_location(year, day, i + 1), &e.input)?;
+        for (part, answer) in e.answers.iter() {
+            expected.push_str(&format!("{} {} {}\n", i + 1, part, escape(answer)));
+        }
+    }
+    std::fs::write(expected_location(year, day), expected)
+}
+
+pub fn load(year: u16, day: u8) -> std::io::Result<Vec<Example>> {
+    let path = expected_location(year, day);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let invalid = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+    let mut examples: Vec<Example> = vec![];
+    for (i, l) in std::fs::read_to_string(path)?.lines().enumerate() {
+        if l.trim().is_empty() {
+            continue;
+        }
+        let mut it = l.splitn(3, ' ');
+        let (Some(n), Some(part), Some(answer)) = (it.next(), it.next(), it.next()) else {
+            return Err(invalid(format!("line {}: expected `N PART ANSWER`", i + 1)));
+        };
+        let n: usize = n
+            .parse()
+            .map_err(|_| invalid(format!("line {}: invalid example {}", i + 1, n)))?;
+        let part: u8 = part
+            .parse()
+            .map_err(|_| invalid(format!("line {}: invalid part {}", i + 1, part)))?;
+        while examples.len() < n {
+            let input = std::fs::read_to_string(example_location(year, day, examples.len() + 1))?;
+            examples.push(Example {
+                input,
+                answers: vec![],
+            });
+        }
+        examples[n - 1].answers.push((part, unescape(answer)));
+    }
+    Ok(examples)
+}
+
+pub struct Failure {
+    pub example: usize,
+    pub part: u8,
+    pub message: String,
+}
+
+// Runs the implemented parts of a day over its stored examples.
+pub fn check(day: &Day) -> std::io::Result<Vec<Failure>> {
+    let mut failures = vec![];
+    for (i, e) in load(day.year, day.day)?.iter().enumerate() {
+        for (part, expected) in e.answers.iter() {
+            if !day.parts.contains(part) {
+                continue;
+            }
+            let message = match solve_catching(day, &e.input, *part == 1) {
+                Ok(s) if &s.answer.to_string() == expected => continue,
+                Ok(s) => format!("got {}, expected {}", s.answer, expected),
+                Err(e) => e,
+            };
+            failures.push(Failure {
+                example: i + 1,
+                part: *part,
+                message,
+            });
+        }
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solutions::registry::registry;
+
+    // Runs from the crate root, where the 2023 examples are committed.
+    #[test]
+    fn stored_examples() {
+        let mut failures = vec![];
+        let mut stored = 0;
+        for d in registry().days() {
+            stored += super::load(d.year, d.day).unwrap().len();
+            for f in super::check(d).unwrap() {
+                failures.push(format!(
+                    "{} day {} example {} part {}: {}",
+                    d.year, d.day, f.example, f.part, f.message
+                ));
+            }
+        }
+        assert!(stored > 0, "no examples stored");
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use crate::answers::{escape, unescape};
+use crate::client::Outcome;
+
+// Every answer submitted for a day, stored as `PART VERDICT ANSWER` lines in
+// `YEAR/DAY/guesses` next to the input.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GuessLog {
+    guesses: Vec<Guess>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Unjudged,
+}
+
+impl Verdict {
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Unjudged => "unjudged",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Unjudged,
+        ]
+        .into_iter()
+        .find(|v| v.name() == name)
+    }
+}
+
+impl From<&Outcome> for Verdict {
+    fn from(outcome: &Outcome) -> Self {
+        match outcome {
+            Outcome::Correct => Verdict::Correct,
+            Outcome::Wrong => Verdict::Wrong,
+            Outcome::TooHigh => Verdict::TooHigh,
+            Outcome::TooLow => Verdict::TooLow,
+            Outcome::RateLimited(_) | Outcome::AlreadySolved | Outcome::Unknown(_) => {
+                Verdict::Unjudged
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadyCorrect(String),
+    KnownWrong,
+    TooHigh(String),
+    TooLow(String),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadyCorrect(a) => write!(f, "already solved with {}", a),
+            Rejection::KnownWrong => write!(f, "already submitted and wrong"),
+            Rejection::TooHigh(bound) => write!(f, "too high, {} was already too high", bound),
+            Rejection::TooLow(bound) => write!(f, "too low, {} was already too low", bound),
+        }
+    }
+}
+
+impl GuessLog {
+    pub fn location(year: u16, day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/{}/guesses", year, day))
+    }
+
+    pub fn load(year: u16, day: u8) -> std::io::Result<Self> {
+        let path = Self::location(year, day);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut guesses = vec![];
+        for (i, l) in content.lines().enumerate() {
+            if l.trim().is_empty() {
+                continue;
+            }
+            let mut it = l.splitn(3, ' ');
+            let (Some(part), Some(verdict), Some(answer)) = (it.next(), it.next(), it.next())
+            else {
+                return Err(format!("line {}: expected `PART VERDICT ANSWER`", i + 1));
+            };
+            let part = part
+                .parse()
+                .map_err(|_| format!("line {}: invalid part {}", i + 1, part))?;
+            let verdict = Verdict::from_name(verdict)
+                .ok_or_else(|| format!("line {}: invalid verdict {}", i + 1, verdict))?;
+            guesses.push(Guess {
+                part,
+                verdict,
+                answer: unescape(answer),
+            });
+        }
+        Ok(GuessLog { guesses })
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> std::io::Result<()> {
+        std::fs::create_dir_all(format!("{}/{}", year, day))?;
+        std::fs::write(Self::location(year, day), self.to_string())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, outcome: &Outcome) {
+        self.guesses.push(Guess {
+            part,
+            verdict: outcome.into(),
+            answer: answer.to_owned(),
+        });
+    }
+
+    pub fn guesses(&self, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |g| g.part == part)
+    }
+
+    // Checks a candidate against previous verdicts so that answers known to be
+    // wrong are rejected locally instead of costing a lockout.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Rejection> {
+        let value = answer.trim().parse::<i128>().ok();
+        let mut lowest_high: Option<(i128, &str)> = None;
+        let mut highest_low: Option<(i128, &str)> = None;
+        for g in self.guesses(part) {
+            match g.verdict {
+                Verdict::Correct => return Err(Rejection::AlreadyCorrect(g.answer.clone())),
+                Verdict::Unjudged => continue,
+                _ if g.answer.trim() == answer.trim() => return Err(Rejection::KnownWrong),
+                _ => {}
+            }
+            let Ok(v) = g.answer.trim().parse::<i128>() else {
+                continue;
+            };
+            match g.verdict {
+                Verdict::TooHigh if lowest_high.is_none_or(|(h, _)| v < h) => {
+                    lowest_high = Some((v, &g.answer))
+                }
+                Verdict::TooLow if highest_low.is_none_or(|(l, _)| v > l) => {
+                    highest_low = Some((v, &g.answer))
+                }
+                _ => {}
+            }
+        }
+        if let Some(value) = value {
+            if let Some((_, a)) = lowest_high.filter(|(h, _)| value >= *h) {
+                return Err(Rejection::TooHigh(a.to_owned()));
+            }
+            if let Some((_, a)) = highest_low.filter(|(l, _)| value <= *l) {
+                return Err(Rejection::TooLow(a.to_owned()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for GuessLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for g in self.guesses.iter() {
+            writeln!(f, "{} {} {}", g.part, g.verdict.name(), escape(&g.answer))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuessLog, Rejection};
+    use crate::client::Outcome;
+
+    #[test]
+    fn bounds() {
+        let mut log = GuessLog::default();
+        log.record(1, "100", &Outcome::TooHigh);
+        log.record(1, "150", &Outcome::TooHigh);
+        log.record(1, "20", &Outcome::TooLow);
+        log.record(1, "42", &Outcome::Wrong);
+        log.record(1, "43", &Outcome::RateLimited(None));
+        log.record(2, "7", &Outcome::Correct);
+
+        assert_eq!(log.check(1, "100"), Err(Rejection::KnownWrong));
+        assert_eq!(log.check(1, "120"), Err(Rejection::TooHigh("100".into())));
+        assert_eq!(log.check(1, "3"), Err(Rejection::TooLow("20".into())));
+        assert_eq!(log.check(1, "42"), Err(Rejection::KnownWrong));
+        assert_eq!(log.check(1, "43"), Ok(()));
+        assert_eq!(log.check(1, "99"), Ok(()));
+        assert_eq!(log.check(1, "abc"), Ok(()));
+        log.record(1, "abc", &Outcome::Wrong);
+        assert_eq!(log.check(1, " abc\n"), Err(Rejection::KnownWrong));
+        assert_eq!(
+            log.check(2, "8"),
+            Err(Rejection::AlreadyCorrect("7".into()))
+        );
+
+        let parsed = GuessLog::parse(&log.to_string()).unwrap();
+        assert_eq!(parsed, log);
+    }
+}
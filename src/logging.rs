@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+// Logger of the solver diagnostics, written on stderr so they never mix with
+// the answers. Solvers log through the `log` macros: the target is then the
+// module of the day, which `--trace` selects.
+pub struct Logger {
+    // Level of every day; other crates only get their warnings through
+    level: LevelFilter,
+    // Days logged at the trace level whatever `level`
+    traced: Vec<(u16, u8)>,
+}
+
+// Year and day of a target like `advent::solutions::y2023::d17`.
+fn target_day(target: &str) -> Option<(u16, u8)> {
+    let mut parts = target.split("::").skip_while(|p| *p != "solutions").skip(1);
+    let year = parts.next()?.strip_prefix('y')?.parse().ok()?;
+    let day = parts.next()?.strip_prefix(['d', 'r'])?.rsplit('_').next()?;
+    Some((year, day.parse().ok()?))
+}
+
+impl Logger {
+    pub fn new(verbose: u8, traced: Vec<(u16, u8)>) -> Self {
+        let level = match verbose {
+            0 => LevelFilter::Off,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+        Logger { level, traced }
+    }
+
+    // Silent by default: nothing is formatted unless asked for.
+    pub fn install(self) -> Result<(), log::SetLoggerError> {
+        let max = if self.traced.is_empty() {
+            self.level
+        } else {
+            LevelFilter::Trace
+        };
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max);
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let level = if metadata.target().starts_with("advent::") {
+            self.level
+        } else {
+            self.level.min(LevelFilter::Warn)
+        };
+        if metadata.level() <= level {
+            return true;
+        }
+        target_day(metadata.target()).is_some_and(|day| self.traced.contains(&day))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let source = match target_day(record.target()) {
+            Some((year, day)) => format!("{}/{}", year, day),
+            None => record.target().to_owned(),
+        };
+        let level = match record.level() {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        let _ = writeln!(
+            std::io::stderr().lock(),
+            "[{} {}] {}",
+            level,
+            source,
+            record.args()
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+// Parses the `YEAR/DAY` argument of `--trace`.
+pub fn parse_day(s: &str) -> Result<(u16, u8), String> {
+    let (year, day) = s
+        .split_once('/')
+        .ok_or_else(|| format!("expected YEAR/DAY, got `{}`", s))?;
+    Ok((
+        year.parse()
+            .map_err(|_| format!("invalid year `{}`", year))?,
+        day.parse().map_err(|_| format!("invalid day `{}`", day))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use log::{Level, Log, Metadata};
+
+    use super::{parse_day, target_day, Logger};
+
+    #[test]
+    fn targets() {
+        assert_eq!(
+            target_day("advent::solutions::y2023::d17"),
+            Some((2023, 17))
+        );
+        assert_eq!(
+            target_day("advent::solutions::y2018::r2018_5"),
+            Some((2018, 5))
+        );
+        assert_eq!(target_day("advent::solutions::common"), None);
+        assert_eq!(target_day("reqwest::connect"), None);
+    }
+
+    #[test]
+    fn filtering() {
+        let meta = |level, target| Metadata::builder().level(level).target(target).build();
+        let logger = Logger::new(0, vec![(2023, 17)]);
+        assert!(logger.enabled(&meta(Level::Trace, "advent::solutions::y2023::d17")));
+        assert!(!logger.enabled(&meta(Level::Error, "advent::solutions::y2023::d20")));
+
+        let logger = Logger::new(1, vec![]);
+        assert!(logger.enabled(&meta(Level::Debug, "advent::solutions::y2023::d20")));
+        assert!(!logger.enabled(&meta(Level::Trace, "advent::solutions::y2023::d20")));
+        assert!(!logger.enabled(&meta(Level::Debug, "reqwest::connect")));
+        assert!(logger.enabled(&meta(Level::Warn, "reqwest::connect")));
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_day("2023/17"), Ok((2023, 17)));
+        assert!(parse_day("2023").is_err());
+        assert!(parse_day("2023/x").is_err());
+    }
+}
@@ -1,75 +1,874 @@
-use clap::{Parser, Subcommand};
-use std::{error::Error, path::PathBuf};
+use clap::{ArgAction, Args, Parser, Subcommand};
+use itertools::Itertools;
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    error::Error,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
+mod answers;
+mod client;
+mod examples;
+mod guesses;
+mod logging;
+mod puzzle;
+mod redirect;
+mod report;
+mod scaffold;
 mod solutions;
+mod summary;
 
-fn get_session() -> Result<String, Box<dyn Error>> {
-    let home = dirs::home_dir().unwrap();
-    let session_file = home.join(".aoc");
-    std::fs::read_to_string(session_file)
-        .map(|s| s.trim().to_owned())
-        .map_err(From::from)
-}
-
-async fn download_input(year: &str, day: &str) -> Result<(), Box<dyn Error>> {
-    let session = get_session()?;
-    let client = reqwest::Client::builder().build()?;
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let res = client
-        .get(&url)
-        .header("cookie", format!("session={}", session))
-        .send()
-        .await?
-        .text()
-        .await?;
+use report::{MetricsFormat, MetricsSink, OutputFormat};
+use solutions::answer::Answer;
+use solutions::cancel;
+use solutions::intcode;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOC: solutions::metrics::alloc::CountingAlloc = solutions::metrics::alloc::CountingAlloc;
+
+fn aoc_client(opts: &Options) -> Result<client::AocClient, Box<dyn Error>> {
+    client::AocClient::new(&opts.base_url, client::get_session()?)
+}
+
+async fn download_input(opts: &Options, year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let res = aoc_client(opts)?.input(year, day).await?;
     std::fs::create_dir_all(format!("{}/{}", year, day))?;
-    std::fs::write(get_input_location(year, day), res)?;
+    std::fs::write(get_input_location(year, day, None), res)?;
     Ok(())
 }
 
-fn get_input_location(year: &str, day: &str) -> PathBuf {
-    PathBuf::from(format!("{}/{}/input", year, day))
+// Inputs other than the puzzle input of the account, like those of teammates
+// or hand-built edge cases, are kept next to it as `input.NAME`.
+fn get_input_location(year: u16, day: u8, name: Option<&str>) -> PathBuf {
+    match name {
+        None => PathBuf::from(format!("{}/{}/input", year, day)),
+        Some(name) => PathBuf::from(format!("{}/{}/input.{}", year, day, name)),
+    }
+}
+
+// Parses the NAME of `--input`, which ends up in file names: it cannot leave
+// the directory of the day.
+fn parse_input_name(name: &str) -> Result<String, String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "invalid input name `{}`, only letters, digits, `-` and `_` are allowed",
+            name
+        ));
+    }
+    Ok(name.to_owned())
+}
+
+// Inputs kept in the directory of a day, the puzzle input of the account
+// first.
+fn input_names(dir: &Path) -> std::io::Result<Vec<Option<String>>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut names = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        match entry?.file_name().to_str() {
+            Some("input") => names.push(None),
+            Some(file) => {
+                if let Some(name) = file.strip_prefix("input.") {
+                    names.push(Some(name.to_owned()));
+                }
+            }
+            None => {}
+        }
+    }
+    names.sort();
+    Ok(names)
 }
 
-async fn run_input(year: &str, day: &str, part: &str) -> Result<(), Box<dyn Error>> {
-    let input_path = get_input_location(year, day);
+async fn load_input(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    name: Option<&str>,
+) -> Result<String, Box<dyn Error>> {
+    let input_path = get_input_location(year, day, name);
     if !input_path.exists() {
-        download_input(year, day).await?;
+        match name {
+            None if opts.offline => {
+                return Err(format!(
+                    "no input for {} day {}, not downloading it while offline",
+                    year, day
+                )
+                .into())
+            }
+            None => download_input(opts, year, day).await?,
+            Some(name) => {
+                return Err(format!("no input named `{}` for {} day {}", name, year, day).into())
+            }
+        }
+    }
+    Ok(std::fs::read_to_string(input_path)?)
+}
+
+// Input of a single run: the given file or stdin, else a kept input.
+async fn read_input(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    name: Option<&str>,
+    source: &SourceArgs,
+) -> Result<String, Box<dyn Error>> {
+    match (&source.input_file, source.stdin) {
+        (Some(path), _) => std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e).into()),
+        (None, true) => Ok(std::io::read_to_string(std::io::stdin())?),
+        (None, false) => load_input(opts, year, day, name).await,
+    }
+}
+
+async fn run_input(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    part: &str,
+    input: Option<&str>,
+    source: &SourceArgs,
+    run: &RunOptions,
+) -> Result<(), Box<dyn Error>> {
+    let solver = solutions::registry::registry().get(year, day)?;
+    let part1 = part == "1";
+    let number = if part1 { 1 } else { 2 };
+    if run.format == OutputFormat::Json {
+        solutions::progress::enable(false);
+        let result = match read_input(opts, year, day, input, source).await {
+            Ok(input) => run.traced(|| {
+                redirect::stdout_to_stderr(|| {
+                    cancel::run(&run.token(), || {
+                        summary::solve_catching(solver, &input, part1)
+                    })
+                })
+            })?,
+            Err(e) => Err(format!("cannot load input: {}", e)),
+        };
+        if let (Some(mut sink), Ok(solved)) = (run.sink(), &result) {
+            sink.record(year, day, number, solved)?;
+        }
+        println!("{}", report::to_json(year, day, number, &result));
+        return result.map(|_| ()).map_err(Into::into);
+    }
+
+    solver.check_part(part1)?;
+    let input = read_input(opts, year, day, input, source).await?;
+    let res = run.traced(|| cancel::run(&run.token(), || solver.solve(&input, part1)))??;
+    if let Some(mut sink) = run.sink() {
+        sink.record(year, day, number, &res)?;
+    }
+    match &res.answer {
+        Answer::Grid(_) => println!("Result is\n{}", res.answer),
+        answer => println!("Result is {}", answer),
+    }
+    Ok(())
+}
+
+async fn run_many(
+    opts: &Options,
+    year: Option<u16>,
+    input: Option<&str>,
+    run: &RunOptions,
+) -> Result<(), Box<dyn Error>> {
+    let json = run.format == OutputFormat::Json;
+    if json {
+        solutions::progress::enable(false);
+    }
+    // The summary already shows the timings in text
+    let mut sink = run.sink().filter(|_| run.metrics != MetricsFormat::Text);
+    let days = solutions::registry::registry().select(year, None)?;
+    let mut summary = summary::Summary::default();
+    let mut results_json = vec![];
+    for d in days.into_iter().filter(|d| !d.parts.is_empty()) {
+        let input = load_input(opts, d.year, d.day, input)
+            .await
+            .map_err(|e| e.to_string());
+        let results = match &input {
+            Ok(input) if json => redirect::stdout_to_stderr(|| {
+                cancel::run(&run.token(), || summary::solve_all_catching(d, input))
+            }),
+            Ok(input) => cancel::run(&run.token(), || summary::solve_all_catching(d, input)),
+            Err(e) => d
+                .parts
+                .iter()
+                .map(|&part| (part, Err(format!("cannot load input: {}", e))))
+                .collect(),
+        };
+        for (part, result) in results {
+            if let (Some(sink), Ok(solved)) = (sink.as_mut(), &result) {
+                sink.record(d.year, d.day, part, solved)?;
+            }
+            if json {
+                results_json.push(report::to_json(d.year, d.day, part, &result));
+            }
+            summary.push(summary::Row {
+                year: d.year,
+                day: d.day,
+                part,
+                result,
+            });
+        }
+    }
+    if json {
+        println!("{}", serde_json::Value::Array(results_json));
+    } else {
+        summary.print();
+    }
+    if summary.failures() > 0 {
+        return Err(format!("{} part(s) failed", summary.failures()).into());
+    }
+    Ok(())
+}
+
+async fn bench_input(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    part: Option<&str>,
+    bench_opts: &solutions::bench::BenchOptions,
+) -> Result<(), Box<dyn Error>> {
+    let solver = solutions::registry::registry().get(year, day)?;
+    let parts = match part {
+        Some(p) => vec![p == "1"],
+        None => vec![true, false]
+            .into_iter()
+            .filter(|&p| solver.has_part(p))
+            .collect(),
+    };
+    for &part1 in parts.iter() {
+        solver.check_part(part1)?;
+    }
+    let input = load_input(opts, year, day, None).await?;
+    println!(
+        "Benchmarking {} day {} ({} warm-up, {} iterations)",
+        year, day, bench_opts.warmup, bench_opts.iterations
+    );
+    for part1 in parts {
+        let report = solver.bench(&input, part1, bench_opts)?;
+        println!("{}", report);
+    }
+    Ok(())
+}
+
+async fn verify(
+    opts: &Options,
+    year: Option<u16>,
+    day: Option<u8>,
+    record: bool,
+    inputs: &InputArgs,
+) -> Result<(), Box<dyn Error>> {
+    let days = solutions::registry::registry().select(year, day)?;
+    let mut stores = BTreeMap::new();
+    let mut failures = 0;
+    let mut recorded = 0;
+    for d in days.into_iter().filter(|d| !d.parts.is_empty()) {
+        for name in inputs.names(d.year, d.day)? {
+            let store = match stores.entry((d.year, name.clone())) {
+                Entry::Occupied(e) => e.into_mut(),
+                Entry::Vacant(e) => e.insert(answers::AnswerStore::load(d.year, name.as_deref())?),
+            };
+            let input = load_input(opts, d.year, d.day, name.as_deref())
+                .await
+                .map_err(|e| e.to_string());
+            let results = match &input {
+                Ok(input) => summary::solve_all_catching(d, input),
+                Err(e) => d
+                    .parts
+                    .iter()
+                    .map(|&part| (part, Err(format!("cannot load input: {}", e))))
+                    .collect(),
+            };
+            let label = match &name {
+                _ if !inputs.named() => String::new(),
+                Some(name) => format!(" {:<10}", name),
+                None => format!(" {:<10}", "-"),
+            };
+            for (part, result) in results {
+                let status = match result {
+                    Err(e) => {
+                        failures += 1;
+                        format!("ERROR     {}", e)
+                    }
+                    Ok(s) => match store.check(d.day, part, &s.answer) {
+                        answers::Verdict::Correct => format!("ok        {}", s.answer),
+                        answers::Verdict::Mismatch(expected) => {
+                            failures += 1;
+                            format!("MISMATCH  got {}, expected {}", s.answer, expected)
+                        }
+                        answers::Verdict::Unknown if record => {
+                            store.set(d.day, part, s.answer.clone());
+                            recorded += 1;
+                            format!("recorded  {}", s.answer)
+                        }
+                        answers::Verdict::Unknown => format!("unknown   {}", s.answer),
+                    },
+                };
+                println!("{} {:>2} {}{}  {}", d.year, d.day, part, label, status);
+            }
+        }
+    }
+    if record {
+        for ((year, name), store) in stores.iter() {
+            store.save(*year, name.as_deref())?;
+        }
+        println!("{} new answer(s) recorded", recorded);
+    }
+    if failures > 0 {
+        return Err(format!("{} part(s) failed verification", failures).into());
+    }
+    Ok(())
+}
+
+async fn submit(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = solutions::registry::registry().get(year, day)?;
+            solver.check_part(part == 1)?;
+            let input = load_input(opts, year, day, None).await?;
+            solver.solve(&input, part == 1)?.answer.to_string()
+        }
+    };
+    let mut log = guesses::GuessLog::load(year, day)?;
+    if let Err(rejection) = log.check(part, &answer) {
+        if !force {
+            return Err(format!("not submitting {}: {}", answer, rejection).into());
+        }
+        println!("Warning: {}", rejection);
+    }
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    let outcome = aoc_client(opts)?.submit(year, day, part, &answer).await?;
+    println!("{}", outcome);
+    log.record(part, &answer, &outcome);
+    log.save(year, day)?;
+    if outcome == client::Outcome::Correct {
+        let mut store = answers::AnswerStore::load(year, None)?;
+        store.set(day, part, Answer::parse(&answer));
+        store.save(year, None)?;
+    }
+    Ok(())
+}
+
+async fn show_puzzle(
+    opts: &Options,
+    year: u16,
+    day: u8,
+    refresh: bool,
+) -> Result<(), Box<dyn Error>> {
+    let html_path = puzzle::html_location(year, day);
+    let html = if html_path.exists() && !refresh {
+        std::fs::read_to_string(html_path)?
+    } else {
+        let html = aoc_client(opts)?.puzzle(year, day).await?;
+        std::fs::create_dir_all(format!("{}/{}", year, day))?;
+        std::fs::write(html_path, &html)?;
+        html
+    };
+    let md = puzzle::markdown(&html);
+    if md.trim().is_empty() {
+        return Err(format!("no puzzle description found for {} day {}", year, day).into());
+    }
+    std::fs::write(puzzle::markdown_location(year, day), &md)?;
+    print!("{}", md);
+    if refresh || !examples::expected_location(year, day).exists() {
+        let found = puzzle::examples(&html);
+        examples::save(year, day, &found)?;
+        println!("\n{} example(s) saved under {}/{}", found.len(), year, day);
+    }
+    Ok(())
+}
+
+fn run_examples(year: Option<u16>, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let mut failures = 0;
+    for d in solutions::registry::registry().select(year, day)? {
+        let stored = examples::load(d.year, d.day)?;
+        if stored.is_empty() {
+            continue;
+        }
+        let failed = examples::check(d)?;
+        println!(
+            "{} {:>2}  {} example(s), {} failure(s)",
+            d.year,
+            d.day,
+            stored.len(),
+            failed.len()
+        );
+        for f in failed.iter() {
+            println!("  example {} part {}: {}", f.example, f.part, f.message);
+        }
+        failures += failed.len();
+    }
+    if failures > 0 {
+        return Err(format!("{} example(s) failed", failures).into());
     }
-    let input = std::fs::read_to_string(input_path)?;
-    let res = solutions::run(year, day, part == "1", &input)?;
-    println!("Result is {}", res);
     Ok(())
 }
 
+fn new_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let html_path = puzzle::html_location(year, day);
+    let title = if html_path.exists() {
+        puzzle::title(&std::fs::read_to_string(html_path)?)
+    } else {
+        None
+    };
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+    let examples = examples::load(year, day)?;
+    let path = scaffold::generate(Path::new("."), year, day, &title, &examples)?;
+    println!(
+        "Created {} ({}, {} example(s))",
+        path.display(),
+        title,
+        examples.len()
+    );
+    Ok(())
+}
+
+fn parse_year(year: &str) -> Result<Option<u16>, Box<dyn Error>> {
+    match year {
+        "all" => Ok(None),
+        year => Ok(Some(year.parse()?)),
+    }
+}
+
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    secs.parse::<f64>()
+        .ok()
+        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+        .ok_or_else(|| format!("invalid number of seconds `{}`", secs))
+}
+
+fn list_days(year: Option<u16>) -> Result<(), Box<dyn Error>> {
+    for d in solutions::registry::registry().select(year, None)? {
+        let parts = if d.parts.is_empty() {
+            "-".to_owned()
+        } else {
+            d.parts.iter().join(", ")
+        };
+        println!("{} {:>2}  {:<32} {}", d.year, d.day, d.title, parts);
+    }
+    Ok(())
+}
+
+fn disassemble(file: &Path) -> Result<(), Box<dyn Error>> {
+    let program = intcode::Program::parse(&std::fs::read_to_string(file)?)?;
+    print!("{}", program.disassemble());
+    Ok(())
+}
+
+fn assemble(file: &Path) -> Result<(), Box<dyn Error>> {
+    let program = intcode::Program::assemble(&std::fs::read_to_string(file)?)?;
+    println!("{}", program.code.iter().join(","));
+    Ok(())
+}
+
+fn profile(file: &Path) -> Result<(), Box<dyn Error>> {
+    let trace = std::io::BufReader::new(std::fs::File::open(file)?);
+    print!("{}", intcode::trace::Profile::read(trace)?);
+    Ok(())
+}
+
+// Debugger prompt: the state of the program is redrawn after each command,
+// and an empty line repeats the last step, continue or back.
+fn debug(file: &Path) -> Result<(), Box<dyn Error>> {
+    use intcode::debug::{Command, Debugger};
+    use std::io::Write;
+
+    let program = intcode::Program::parse(&std::fs::read_to_string(file)?)?;
+    let mut debugger = Debugger::new(program);
+    let mut message = "`help` lists the commands".to_owned();
+    let mut last = None;
+    loop {
+        let mut stdout = std::io::stdout().lock();
+        write!(
+            stdout,
+            "{}{}",
+            termion::clear::All,
+            termion::cursor::Goto(1, 1)
+        )?;
+        for line in debugger.view().lines() {
+            if line.starts_with('>') {
+                let (bold, reset) = (termion::style::Bold, termion::style::Reset);
+                writeln!(stdout, "{}{}{}", bold, line, reset)?;
+            } else {
+                writeln!(stdout, "{}", line)?;
+            }
+        }
+        write!(stdout, "\n{}\n> ", message)?;
+        stdout.flush()?;
+        drop(stdout);
+
+        let mut line = String::new();
+        if std::io::stdin().read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        let command = match (line.trim(), &last) {
+            ("", Some(command)) => Ok(Command::clone(command)),
+            (line, _) => Command::parse(line),
+        };
+        match command {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(command) => {
+                if matches!(
+                    command,
+                    Command::Step(_) | Command::Continue | Command::Back(_)
+                ) {
+                    last = Some(command.clone());
+                }
+                message = debugger.execute(command);
+            }
+            Err(e) => message = e,
+        }
+    }
+}
+
 #[derive(Parser)]
 #[command()]
 struct Cli {
+    #[command(flatten)]
+    opts: Options,
     #[command(subcommand)]
     command: Command,
 }
 
+#[derive(Args)]
+struct Options {
+    /// Advent of Code server the inputs, puzzles and answers go through
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Logs the solver diagnostics on stderr, `-vv` for the trace level
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Logs everything from a day, as YEAR/DAY
+    #[arg(long, global = true, value_name = "YEAR/DAY", value_parser = logging::parse_day)]
+    trace: Vec<(u16, u8)>,
+    /// Fails instead of downloading a missing puzzle input
+    #[arg(long, global = true)]
+    offline: bool,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Runs on `YEAR/DAY/input.NAME` instead of the puzzle input
+    #[arg(long, value_name = "NAME", value_parser = parse_input_name)]
+    input: Option<String>,
+    /// Checks every input kept for the days against its recorded answers
+    #[arg(long, conflicts_with = "input")]
+    all_inputs: bool,
+}
+
+impl InputArgs {
+    fn named(&self) -> bool {
+        self.all_inputs || self.input.is_some()
+    }
+
+    fn names(&self, year: u16, day: u8) -> std::io::Result<Vec<Option<String>>> {
+        if self.all_inputs {
+            input_names(Path::new(&format!("{}/{}", year, day)))
+        } else {
+            Ok(vec![self.input.clone()])
+        }
+    }
+}
+
+#[derive(Args)]
+struct SourceArgs {
+    /// Runs on any file, like a generated input
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["input", "all_inputs", "stdin"])]
+    input_file: Option<PathBuf>,
+    /// Reads the input from stdin
+    #[arg(long, conflicts_with_all = ["input", "all_inputs"])]
+    stdin: bool,
+}
+
+#[derive(Args)]
+struct RunOptions {
+    /// `json` prints a single document on stdout, the solvers output goes to
+    /// stderr
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+    /// Where the timings and counters of the solved parts go
+    #[arg(long, value_enum, default_value = "text")]
+    metrics: MetricsFormat,
+    /// CSV file the metrics are appended to
+    #[arg(long, default_value = "metrics.csv")]
+    history: PathBuf,
+    /// Cancels a day still running after that many seconds
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+    /// Records the Intcode instructions executed to a trace file, and prints
+    /// where they went on stderr
+    #[arg(long, value_name = "FILE")]
+    intcode_trace: Option<PathBuf>,
+    /// Records only one instruction in N to the trace
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 1,
+        requires = "intcode_trace"
+    )]
+    intcode_sample: u64,
+}
+
+impl RunOptions {
+    fn token(&self) -> cancel::Token {
+        cancel::Token::new(self.timeout)
+    }
+
+    // Only the CSV history stays off stdout when the output is JSON
+    fn sink(&self) -> Option<Box<dyn MetricsSink>> {
+        match (self.format, self.metrics) {
+            (OutputFormat::Json, MetricsFormat::Text | MetricsFormat::Json) => None,
+            (_, format) => Some(report::sink(format, self.history.clone())),
+        }
+    }
+
+    fn traced<T>(&self, f: impl FnOnce() -> T) -> Result<T, Box<dyn Error>> {
+        use intcode::trace::{self, Profile, Tracer};
+        use std::{fs::File, io};
+
+        let Some(path) = &self.intcode_trace else {
+            return Ok(f());
+        };
+        let file =
+            File::create(path).map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+        let tracer = Tracer::new(Box::new(io::BufWriter::new(file)), self.intcode_sample)?;
+        let (res, tracer) = trace::run(tracer, f);
+        tracer.finish()?;
+        let profile = Profile::read(io::BufReader::new(File::open(path)?))?;
+        eprint!("{}", profile);
+        Ok(res)
+    }
+}
+
 #[derive(Subcommand)]
 enum Command {
-    Input {
+    /// Downloads the puzzle input of a day
+    Input { year: u16, day: u8 },
+    /// Solves a part of a day, or every day of a year or of `all`
+    Run {
         year: String,
-        day: String,
+        day: Option<u8>,
+        part: Option<String>,
+        #[command(flatten)]
+        run: RunOptions,
+        #[command(flatten)]
+        inputs: InputArgs,
+        #[command(flatten)]
+        source: SourceArgs,
     },
-    Run {
+    /// Lists the days and their implemented parts
+    List { year: Option<u16> },
+    /// Creates the solver module of a day from the template
+    New { year: u16, day: u8 },
+    /// Prints the puzzle description and saves its examples
+    Puzzle {
+        year: u16,
+        day: u8,
+        /// Downloads the description again, and extracts the examples again
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Checks the solvers against the stored examples
+    Examples { year: String, day: Option<u8> },
+    /// Submits an answer, solving the part when none is given
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: Option<String>,
+        /// Submits even an answer the guess log rules out
+        #[arg(long)]
+        force: bool,
+    },
+    /// Checks the answers of the solvers against the recorded ones
+    Verify {
         year: String,
-        day: String,
+        day: Option<u8>,
+        /// Records the answers not known yet
+        #[arg(long)]
+        record: bool,
+        #[command(flatten)]
+        inputs: InputArgs,
+    },
+    /// Times the parse and the parts of a day over many runs
+    Bench {
+        year: u16,
+        day: u8,
         part: Option<String>,
+        /// Timed runs
+        #[arg(short, long, default_value_t = 100)]
+        iterations: usize,
+        /// Untimed runs first
+        #[arg(short, long, default_value_t = 10)]
+        warmup: usize,
+    },
+    /// Tools for the Intcode programs of 2019
+    Intcode {
+        #[command(subcommand)]
+        command: IntcodeCommand,
     },
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let cli = Cli::parse();
+#[derive(Subcommand)]
+enum IntcodeCommand {
+    /// Prints an annotated listing of a program
+    Disasm { file: PathBuf },
+    /// Prints the program of an assembly source
+    Asm { file: PathBuf },
+    /// Steps through a program, `help` at the prompt lists the commands
+    Debug { file: PathBuf },
+    /// Prints the hot spots of a trace recorded by `run --intcode-trace`
+    Profile { file: PathBuf },
+}
+
+async fn execute(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let opts = &cli.opts;
     match cli.command {
-        Command::Input { year, day } => download_input(&year, &day).await?,
-        Command::Run { year, day, part } => {
-            run_input(&year, &day, &part.unwrap_or("1".into())).await?
+        Command::Input { year, day } => download_input(opts, year, day).await?,
+        Command::Run {
+            year,
+            day,
+            part,
+            run,
+            inputs,
+            source,
+        } => match (parse_year(&year)?, day) {
+            (None, Some(_)) => return Err("a day cannot be given with `all`".into()),
+            (_, None) if source.input_file.is_some() || source.stdin => {
+                return Err("a day is needed to run on a file or stdin".into())
+            }
+            (_, day) if run.intcode_trace.is_some() && (day.is_none() || inputs.all_inputs) => {
+                return Err("a single day and input are needed to trace Intcode".into())
+            }
+            _ if inputs.all_inputs
+                && (part.is_some()
+                    || run.format != OutputFormat::Text
+                    || run.metrics != MetricsFormat::Text
+                    || run.timeout.is_some()) =>
+            {
+                return Err(
+                    "`--all-inputs` checks both parts against the recorded answers, \
+                     it takes no PART, `--format`, `--metrics` or `--timeout`"
+                        .into(),
+                )
+            }
+            (year, day) if inputs.all_inputs => verify(opts, year, day, false, &inputs).await?,
+            (year, None) => run_many(opts, year, inputs.input.as_deref(), &run).await?,
+            (Some(year), Some(day)) => {
+                let part = part.unwrap_or("1".into());
+                let input = inputs.input.as_deref();
+                run_input(opts, year, day, &part, input, &source, &run).await?
+            }
+        },
+        Command::List { year } => list_days(year)?,
+        Command::New { year, day } => new_day(year, day)?,
+        Command::Puzzle { year, day, refresh } => show_puzzle(opts, year, day, refresh).await?,
+        Command::Examples { year, day } => {
+            let year = parse_year(&year)?;
+            if year.is_none() && day.is_some() {
+                return Err("a day cannot be given with `all`".into());
+            }
+            run_examples(year, day)?
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            force,
+        } => submit(opts, year, day, part, answer, force).await?,
+        Command::Verify {
+            year,
+            day,
+            record,
+            inputs,
+        } => {
+            let year = parse_year(&year)?;
+            if year.is_none() && day.is_some() {
+                return Err("a day cannot be given with `all`".into());
+            }
+            verify(opts, year, day, record, &inputs).await?
         }
+        Command::Bench {
+            year,
+            day,
+            part,
+            iterations,
+            warmup,
+        } => {
+            let bench_opts = solutions::bench::BenchOptions { warmup, iterations };
+            bench_input(opts, year, day, part.as_deref(), &bench_opts).await?
+        }
+        Command::Intcode { command } => match command {
+            IntcodeCommand::Disasm { file } => disassemble(&file)?,
+            IntcodeCommand::Asm { file } => assemble(&file)?,
+            IntcodeCommand::Debug { file } => debug(&file)?,
+            IntcodeCommand::Profile { file } => profile(&file)?,
+        },
     }
     Ok(())
 }
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    logging::Logger::new(cli.opts.verbose, cli.opts.trace.clone())
+        .install()
+        .expect("the logger is only installed once");
+    solutions::progress::enable(termion::is_tty(&std::io::stderr()));
+    if let Err(e) = execute(cli).await {
+        eprintln!("Error: {}", e);
+        if let Some(excerpt) = e
+            .downcast_ref::<solutions::AocError>()
+            .and_then(|e| e.excerpt())
+        {
+            eprintln!("{}", excerpt);
+        }
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_names, parse_input_name};
+
+    #[test]
+    fn input_name() {
+        assert_eq!(parse_input_name("alice-2"), Ok("alice-2".to_owned()));
+        assert!(parse_input_name("").is_err());
+        assert!(parse_input_name("../x").is_err());
+        assert!(parse_input_name("a/b").is_err());
+    }
+
+    #[test]
+    fn names() {
+        let dir = std::env::temp_dir().join(format!("advent-inputs-{}", std::process::id()));
+        assert_eq!(input_names(&dir).unwrap(), vec![]);
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["input.bob", "answers", "input", "input.alice"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+        assert_eq!(
+            input_names(&dir).unwrap(),
+            vec![None, Some("alice".to_owned()), Some("bob".to_owned())]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+use std::path::PathBuf;
+
+use regex::Regex;
+
+pub fn html_location(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("{}/{}/puzzle.html", year, day))
+}
+
+pub fn markdown_location(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("{}/{}/puzzle.md", year, day))
+}
+
+// Inner HTML of every `<article class="day-desc">`, one per unlocked part.
+pub fn articles(html: &str) -> Vec<&str> {
+    let article = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    article
+        .captures_iter(html)
+        .map(|c| c.get(1).unwrap().as_str())
+        .collect()
+}
+
+pub fn title(html: &str) -> Option<String> {
+    let title = Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>").unwrap();
+    title.captures(html).map(|c| decode_entities(&c[1]))
+}
+
+pub fn decode_entities(s: &str) -> String {
+    let entity = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+    entity
+        .replace_all(s, |c: &regex::Captures| {
+            let e = &c[1];
+            let decoded = if let Some(hex) = e.strip_prefix("#x") {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(dec) = e.strip_prefix('#') {
+                dec.parse().ok().and_then(char::from_u32)
+            } else {
+                match e {
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "amp" => Some('&'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some(' '),
+                    _ => None,
+                }
+            };
+            decoded.map_or_else(|| c[0].to_owned(), |d| d.to_string())
+        })
+        .into_owned()
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let tag = Regex::new(r"<(/?)([a-zA-Z0-9]+)([^>]*)>").unwrap();
+    let mut tokens = vec![];
+    let mut last = 0;
+    for c in tag.captures_iter(html) {
+        let m = c.get(0).unwrap();
+        if m.start() > last {
+            tokens.push(Token::Text(&html[last..m.start()]));
+        }
+        let name = c.get(2).unwrap().as_str();
+        if c.get(1).unwrap().as_str() == "/" {
+            tokens.push(Token::Close(name));
+        } else {
+            tokens.push(Token::Open(name, c.get(3).unwrap().as_str()));
+        }
+        last = m.end();
+    }
+    if last < html.len() {
+        tokens.push(Token::Text(&html[last..]));
+    }
+    tokens
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let re = Regex::new(&format!(r#"{}="([^"]*)""#, name)).unwrap();
+    re.captures(attrs).map(|c| c.get(1).unwrap().as_str())
+}
+
+// Converts the subset of HTML used in puzzle descriptions to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links: Vec<String> = vec![];
+    for token in tokenize(html) {
+        match token {
+            Token::Text(t) => {
+                let t = decode_entities(t);
+                if in_pre {
+                    out.push_str(&t);
+                } else {
+                    let collapsed = t.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if t.starts_with(char::is_whitespace) && !out.ends_with([' ', '\n']) {
+                        out.push(' ');
+                    }
+                    out.push_str(&collapsed);
+                    if t.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                        out.push(' ');
+                    }
+                }
+            }
+            Token::Open(name, attrs) => match name {
+                "h2" => out.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "code" if !in_pre => {
+                    in_code = true;
+                    out.push('`');
+                }
+                "em" if !in_pre && !in_code => out.push('*'),
+                "li" => out.push_str("- "),
+                "a" => {
+                    links.push(attr(attrs, "href").unwrap_or_default().to_owned());
+                    out.push('[');
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "h2" | "p" => {
+                    let trimmed = out.trim_end_matches(' ').len();
+                    out.truncate(trimmed);
+                    out.push_str("\n\n");
+                }
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    in_code = false;
+                    out.push('`');
+                }
+                "em" if !in_pre && !in_code => out.push('*'),
+                "li" => {
+                    let trimmed = out.trim_end_matches(' ').len();
+                    out.truncate(trimmed);
+                    out.push('\n');
+                }
+                "ul" => out.push('\n'),
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({})", href));
+                }
+                _ => {}
+            },
+        }
+    }
+    let title = Regex::new(r"(?m)^## --- (.*) ---$").unwrap();
+    let out = title.replace_all(&out, "## $1");
+    format!("{}\n", out.trim_end())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(u8, String)>,
+}
+
+fn strip_tags(html: &str) -> String {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    decode_entities(&tag.replace_all(html, ""))
+}
+
+// Example inputs are the `<pre><code>` blocks of the description, and the
+// expected answer of a part is the last `<code><em>` of its article. Each
+// answer is attached to the closest example before it, which can be in the
+// part 1 article when part 2 reuses its examples.
+pub fn examples(html: &str) -> Vec<Example> {
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    let mut examples: Vec<Example> = vec![];
+    for (part, article) in articles(html).into_iter().enumerate() {
+        let mut last_pre = None;
+        for c in pre.captures_iter(article) {
+            let input = strip_tags(c.get(1).unwrap().as_str());
+            let idx = match examples.iter().position(|e| e.input == input) {
+                Some(idx) => idx,
+                None => {
+                    examples.push(Example {
+                        input,
+                        answers: vec![],
+                    });
+                    examples.len() - 1
+                }
+            };
+            last_pre = Some((c.get(0).unwrap().start(), idx));
+        }
+        let Some(a) = answer.captures_iter(article).last() else {
+            continue;
+        };
+        let pos = a.get(0).unwrap().start();
+        let target = match last_pre {
+            Some((start, idx)) if start < pos => Some(idx),
+            _ => examples.len().checked_sub(1),
+        };
+        if let Some(idx) = target {
+            let value = strip_tags(a.get(1).unwrap().as_str());
+            examples[idx].answers.push((part as u8 + 1, value));
+        }
+    }
+    examples
+}
+
+pub fn markdown(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .map(to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{articles, examples, markdown, title, to_markdown, Example};
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+
+    #[test]
+    fn extract() {
+        let a = articles(PAGE);
+        assert_eq!(a.len(), 2);
+        assert!(a[0].starts_with("<h2>--- Day 4: Example Puzzle ---</h2>"));
+        assert!(a[1].starts_with("<h2 id=\"part2\">--- Part Two ---</h2>"));
+        assert_eq!(title(PAGE).as_deref(), Some("Example Puzzle"));
+    }
+
+    #[test]
+    fn convert() {
+        let md = markdown(PAGE);
+        let expected = include_str!("../fixtures/puzzle.md");
+        assert_eq!(md, expected);
+    }
+
+    #[test]
+    fn extract_examples() {
+        assert_eq!(
+            examples(PAGE),
+            vec![Example {
+                input: "Card 1: 41 48 83 | 83 86  6\nCard 2: 13 32 20 | 61 30 68\n".into(),
+                answers: vec![(1, "13".into()), (2, "30".into())],
+            }]
+        );
+
+        let two = "<article class=\"day-desc\"><pre><code>a</code></pre><p><code><em>1</em></code></p>\
+            <pre><code>&lt;b&gt;</code></pre><p>gives <code><em>2</em></code></p></article>\
+            <article class=\"day-desc\"><pre><code>c</code></pre><p><code><em>3</em></code></p></article>";
+        let e = examples(two);
+        assert_eq!(e.len(), 3);
+        assert_eq!(e[1].input, "<b>");
+        assert_eq!(e[1].answers, vec![(1, "2".into())]);
+        assert_eq!(e[2].answers, vec![(2, "3".into())]);
+        assert!(e[0].answers.is_empty());
+    }
+
+    #[test]
+    fn inline() {
+        assert_eq!(
+            to_markdown("<p>The answer is <code><em>4 &lt; 5</em></code>, <em>see</em> <a href=\"/2023/day/1\">day 1</a>.</p>"),
+            "The answer is `4 < 5`, *see* [day 1](/2023/day/1).\n"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::Write;
+
+// Runs `f` with whatever it writes to stdout sent to stderr instead. Solvers
+// print their debug output with `println!`, which would corrupt a result
+// meant to be read by another program.
+pub fn stdout_to_stderr<T>(f: impl FnOnce() -> T) -> T {
+    let _ = std::io::stdout().flush();
+    let saved = unsafe { libc::dup(libc::STDOUT_FILENO) };
+    if saved < 0 {
+        return f();
+    }
+    if unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+        unsafe { libc::close(saved) };
+        return f();
+    }
+    let _restore = Restore(saved);
+    f()
+}
+
+// Puts the saved stdout back when dropped, even if `f` panicked.
+struct Restore(libc::c_int);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let _ = std::io::stdout().flush();
+        unsafe {
+            libc::dup2(self.0, libc::STDOUT_FILENO);
+            libc::close(self.0);
+        }
+    }
+}
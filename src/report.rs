@@ -0,0 +1,202 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::ValueEnum;
+use itertools::Itertools;
+
+use crate::solutions::metrics::{ANALYZE, COMPUTE, PARSE};
+use crate::solutions::Solved;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MetricsFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+// Destination of the metrics of solved parts.
+pub trait MetricsSink {
+    fn record(&mut self, year: u16, day: u8, part: u8, solved: &Solved) -> std::io::Result<()>;
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub struct Text;
+
+impl MetricsSink for Text {
+    fn record(&mut self, _: u16, _: u8, _: u8, solved: &Solved) -> std::io::Result<()> {
+        let m = &solved.metrics;
+        println!("Run time: {} ms", ms(solved.total()));
+        println!("Parsing: {} ms", ms(solved.parsing()));
+        println!("Compute: {} ms", ms(solved.compute()));
+        if let Some(peak) = m.peak_heap {
+            println!("Peak heap: {} bytes", peak);
+        }
+        for (name, n) in m.counters.iter() {
+            println!("{}: {}", name, n);
+        }
+        Ok(())
+    }
+}
+
+// One JSON object per solved part on stdout.
+pub struct Json;
+
+fn metrics_json(solved: &Solved) -> serde_json::Value {
+    let m = &solved.metrics;
+    let phases: serde_json::Map<String, serde_json::Value> = m
+        .phases
+        .iter()
+        .chain(m.nested.iter())
+        .map(|(name, _)| (name.to_string(), ms(m.phase(name)).into()))
+        .collect();
+    serde_json::json!({
+        "total_ms": ms(solved.total()),
+        "phases_ms": phases,
+        "peak_heap": m.peak_heap,
+        "counters": m.counters,
+    })
+}
+
+// Result of a part as reported by `--format json`. Failed parts have a null
+// answer and metrics, and the error message.
+pub fn to_json(year: u16, day: u8, part: u8, result: &Result<Solved, String>) -> serde_json::Value {
+    let (answer, metrics, error) = match result {
+        Ok(solved) => (
+            Some(solved.answer.to_string()),
+            Some(metrics_json(solved)),
+            None,
+        ),
+        Err(e) => (None, None, Some(e)),
+    };
+    serde_json::json!({
+        "year": year,
+        "day": day,
+        "part": part,
+        "answer": answer,
+        "metrics": metrics,
+        "error": error,
+    })
+}
+
+impl MetricsSink for Json {
+    fn record(&mut self, year: u16, day: u8, part: u8, solved: &Solved) -> std::io::Result<()> {
+        let mut v = metrics_json(solved);
+        v["year"] = year.into();
+        v["day"] = day.into();
+        v["part"] = part.into();
+        println!("{}", v);
+        Ok(())
+    }
+}
+
+// Appends one line per solved part to a CSV file, to track performance over
+// time. Counters are stored as `name=value` pairs separated by `;`.
+pub struct Csv {
+    pub path: PathBuf,
+}
+
+const CSV_HEADER: &str =
+    "timestamp,year,day,part,total_ms,parse_ms,analyze_ms,compute_ms,peak_heap,counters";
+
+pub fn csv_line(timestamp: u64, year: u16, day: u8, part: u8, solved: &Solved) -> String {
+    let m = &solved.metrics;
+    format!(
+        "{},{},{},{},{:.3},{:.3},{:.3},{:.3},{},{}",
+        timestamp,
+        year,
+        day,
+        part,
+        ms(m.total()),
+        ms(m.phase(PARSE)),
+        ms(m.phase(ANALYZE)),
+        ms(m.phase(COMPUTE)),
+        m.peak_heap.map(|p| p.to_string()).unwrap_or_default(),
+        m.counters
+            .iter()
+            .map(|(name, n)| format!("{}={}", name, n))
+            .join(";")
+    )
+}
+
+impl MetricsSink for Csv {
+    fn record(&mut self, year: u16, day: u8, part: u8, solved: &Solved) -> std::io::Result<()> {
+        let new = !self.path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if new {
+            writeln!(file, "{}", CSV_HEADER)?;
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        writeln!(file, "{}", csv_line(timestamp, year, day, part, solved))
+    }
+}
+
+pub fn sink(format: MetricsFormat, history: PathBuf) -> Box<dyn MetricsSink> {
+    match format {
+        MetricsFormat::Text => Box::new(Text),
+        MetricsFormat::Json => Box::new(Json),
+        MetricsFormat::Csv => Box::new(Csv { path: history }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{csv_line, to_json};
+    use crate::solutions::answer::Answer;
+    use crate::solutions::metrics::{Metrics, COMPUTE, PARSE};
+    use crate::solutions::Solved;
+
+    fn solved() -> Solved {
+        Solved {
+            answer: Answer::Integer(42),
+            metrics: Metrics {
+                phases: vec![
+                    (PARSE, Duration::from_millis(2)),
+                    (COMPUTE, Duration::from_millis(5)),
+                ],
+                counters: [("states", 12), ("pops", 3)].into(),
+                ..Metrics::default()
+            },
+        }
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            csv_line(1700000000, 2023, 17, 1, &solved()),
+            "1700000000,2023,17,1,7.000,2.000,0.000,5.000,,pops=3;states=12"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let v = to_json(2023, 17, 2, &Ok(solved()));
+        assert_eq!(v["part"], 2);
+        assert_eq!(v["answer"], "42");
+        assert!(v["error"].is_null());
+        assert_eq!(v["metrics"]["phases_ms"]["compute"], 5.0);
+        assert_eq!(v["metrics"]["counters"]["states"], 12);
+        assert!(v["metrics"]["peak_heap"].is_null());
+
+        let v = to_json(2023, 17, 1, &Err("boom".into()));
+        assert!(v["answer"].is_null());
+        assert!(v["metrics"].is_null());
+        assert_eq!(v["error"], "boom");
+    }
+}
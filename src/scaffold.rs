@@ -0,0 +1,221 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::puzzle::Example;
+
+const TEMPLATE: &str = include_str!("solutions/template.rs");
+
+fn string_literal(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn test_module(day: u8, examples: &[Example]) -> String {
+    let mut tests = vec![];
+    for (n, e) in examples.iter().enumerate() {
+        if e.answers.is_empty() {
+            continue;
+        }
+        let parts = e
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("part{} = {}", part, string_literal(answer)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let body = format!(
+            "        let i = {};\n\n        example_test!(Solution, i, {});\n",
+            string_literal(&e.input),
+            parts
+        );
+        tests.push(format!(
+            "    #[test]\n    fn d{}_example{}() {{\n{}    }}\n",
+            day,
+            n + 1,
+            body
+        ));
+    }
+    if tests.is_empty() {
+        return "mod tests {}\n".to_owned();
+    }
+    format!(
+        "mod tests {{\n    use super::Solution;\n    use crate::solutions::test_support::example_test;\n\n{}}}\n",
+        tests.join("\n")
+    )
+}
+
+pub fn day_source(day: u8, examples: &[Example]) -> String {
+    let template = TEMPLATE.trim_end();
+    let source = template
+        .strip_suffix("mod tests {}")
+        .expect("template ends with an empty test module");
+    format!("{}{}", source, test_module(day, examples))
+}
+
+fn year_module(year: u16) -> String {
+    format!(
+        "use crate::solutions::registry::days;\n\ndays! {{\n    {};\n}}\n",
+        year
+    )
+}
+
+// Inserts `N => dN, "TITLE", [];` into the `days!` invocation, keeping days
+// sorted.
+pub fn register_day(year_mod: &str, day: u8, title: &str) -> Result<String, String> {
+    let entry = Regex::new(r"^\s+(\d+) =>").unwrap();
+    let mut lines: Vec<&str> = year_mod.lines().collect();
+    let mut insert_at = None;
+    for (i, l) in lines.iter().enumerate() {
+        if let Some(c) = entry.captures(l) {
+            let d: u8 = c[1].parse().map_err(|_| format!("invalid entry {}", l))?;
+            if d == day {
+                return Err(format!("day {} is already registered", day));
+            }
+            if d > day && insert_at.is_none() {
+                insert_at = Some(i);
+            }
+        } else if *l == "}" && insert_at.is_none() {
+            insert_at = Some(i);
+        }
+    }
+    let insert_at = insert_at.ok_or("cannot find the end of `days!`")?;
+    let line = format!("    {} => d{}, {}, [];", day, day, string_literal(title));
+    lines.insert(insert_at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds `yYYYY,` to the `years!` invocation, keeping years sorted.
+pub fn register_year(solutions_mod: &str, year: u16) -> Result<String, String> {
+    let module = format!("y{}", year);
+    let mut lines: Vec<&str> = solutions_mod.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("registry::years!"))
+        .ok_or("cannot find `years!`")?;
+    let mut insert_at = None;
+    for (i, l) in lines.iter().enumerate().skip(start + 1) {
+        if *l == "}" {
+            insert_at = Some(i);
+            break;
+        }
+        let m = l.trim().trim_end_matches(',');
+        if m == module {
+            return Err(format!("year {} is already registered", year));
+        }
+        if m > module.as_str() {
+            insert_at = Some(i);
+            break;
+        }
+    }
+    let insert_at = insert_at.ok_or("cannot find the end of `years!`")?;
+    let line = format!("    {},", module);
+    lines.insert(insert_at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Creates `src/solutions/yYYYY/dN.rs` under `root`, creating and registering
+// the year module if needed. Existing days are never overwritten.
+pub fn generate(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+    examples: &[Example],
+) -> Result<PathBuf, Box<dyn Error>> {
+    let solutions = root.join("src/solutions");
+    let year_dir = solutions.join(format!("y{}", year));
+    let day_path = year_dir.join(format!("d{}.rs", day));
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()).into());
+    }
+
+    let year_mod_path = year_dir.join("mod.rs");
+    let new_year = !year_mod_path.exists();
+    let year_mod = if new_year {
+        year_module(year)
+    } else {
+        std::fs::read_to_string(&year_mod_path)?
+    };
+    let year_mod = register_day(&year_mod, day, title)?;
+    let solutions_mod = if new_year {
+        let content = std::fs::read_to_string(solutions.join("mod.rs"))?;
+        Some(register_year(&content, year)?)
+    } else {
+        None
+    };
+
+    std::fs::create_dir_all(&year_dir)?;
+    std::fs::write(&day_path, day_source(day, examples))?;
+    std::fs::write(&year_mod_path, year_mod)?;
+    if let Some(content) = solutions_mod {
+        std::fs::write(solutions.join("mod.rs"), content)?;
+    }
+    Ok(day_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{day_source, generate, register_day, register_year};
+    use crate::puzzle::Example;
+
+    #[test]
+    fn days() {
+        let m = "use crate::solutions::registry::days;\n\ndays! {\n    2023;\n    1 => d1, \"A\", [1, 2];\n    3 => d3, \"C\", [1];\n}\n";
+        let r = register_day(m, 2, "B \"b\"").unwrap();
+        assert_eq!(r, "use crate::solutions::registry::days;\n\ndays! {\n    2023;\n    1 => d1, \"A\", [1, 2];\n    2 => d2, \"B \\\"b\\\"\", [];\n    3 => d3, \"C\", [1];\n}\n");
+        let r = register_day(m, 4, "D").unwrap();
+        assert!(r.ends_with("    3 => d3, \"C\", [1];\n    4 => d4, \"D\", [];\n}\n"));
+        assert!(register_day(m, 3, "C").is_err());
+    }
+
+    #[test]
+    fn years() {
+        let m = "mod common;\n\nregistry::years! {\n    y2018,\n    y2023,\n}\n\npub enum AocError {}\n";
+        let r = register_year(m, 2021).unwrap();
+        assert_eq!(r, "mod common;\n\nregistry::years! {\n    y2018,\n    y2021,\n    y2023,\n}\n\npub enum AocError {}\n");
+        let r = register_year(m, 2024).unwrap();
+        assert!(r.contains("    y2023,\n    y2024,\n}"));
+        assert!(register_year(m, 2023).is_err());
+    }
+
+    #[test]
+    fn source() {
+        let s = day_source(5, &[]);
+        assert!(s.starts_with("use crate::solutions::AocError;"));
+        assert!(s.ends_with("#[cfg(test)]\nmod tests {}\n"));
+
+        let examples = vec![Example {
+            input: "a \"b\"\n".into(),
+            answers: vec![(1, "42".into())],
+        }];
+        let s = day_source(5, &examples);
+        assert!(s.contains("    fn d5_example1() {\n        let i = \"a \\\"b\\\"\n\";\n"));
+        assert!(s.contains("        example_test!(Solution, i, part1 = \"42\");\n"));
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let solutions = root.join("src/solutions");
+        std::fs::create_dir_all(&solutions).unwrap();
+        std::fs::write(
+            solutions.join("mod.rs"),
+            "registry::years! {\n    y2023,\n}\n",
+        )
+        .unwrap();
+
+        let path = generate(&root, 2030, 1, "First", &[]).unwrap();
+        assert_eq!(path, solutions.join("y2030/d1.rs"));
+        assert!(std::fs::read_to_string(solutions.join("mod.rs"))
+            .unwrap()
+            .contains("    y2030,\n"));
+        generate(&root, 2030, 2, "Second", &[]).unwrap();
+        let year_mod = std::fs::read_to_string(solutions.join("y2030/mod.rs")).unwrap();
+        assert!(
+            year_mod.ends_with("    1 => d1, \"First\", [];\n    2 => d2, \"Second\", [];\n}\n")
+        );
+        assert!(generate(&root, 2030, 1, "Again", &[]).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
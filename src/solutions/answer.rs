@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use num::BigInt;
+
+// Answer of a part, whatever the output type of the solver. Grids are the
+// ASCII-art answers, one string per row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    // Classifies a rendered answer. The result always displays back as `s`.
+    pub fn parse(s: &str) -> Answer {
+        if let Some(i) = s.parse::<i64>().ok().filter(|i| i.to_string() == s) {
+            return Answer::Integer(i);
+        }
+        if let Some(i) = s.parse::<BigInt>().ok().filter(|i| i.to_string() == s) {
+            return Answer::BigInteger(i);
+        }
+        if s.contains('\n') {
+            let rows: Vec<String> = s.lines().map(str::to_owned).collect();
+            if rows.join("\n") == s {
+                return Answer::Grid(rows);
+            }
+        }
+        Answer::Text(s.to_owned())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{}", i),
+            Answer::BigInteger(i) => write!(f, "{}", i),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::parse(s)
+    }
+}
+
+// Output types of the solvers. Anything displayable converts through its
+// rendering.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl<T: Display> IntoAnswer for T {
+    fn into_answer(self) -> Answer {
+        Answer::parse(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use super::{Answer, IntoAnswer};
+
+    #[test]
+    fn convert() {
+        assert_eq!(42u8.into_answer(), Answer::Integer(42));
+        assert_eq!((-7i64).into_answer(), Answer::Integer(-7));
+        assert_eq!(
+            u128::MAX.into_answer(),
+            Answer::BigInteger(BigInt::from(u128::MAX))
+        );
+        assert_eq!("abc".into_answer(), Answer::Text("abc".into()));
+        assert_eq!(
+            "#.\n.#".into_answer(),
+            Answer::Grid(vec!["#.".into(), ".#".into()])
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for s in ["+5", "007", "-0", "a\nb\n", "", "1 2", "#.\n.#"] {
+            assert_eq!(Answer::parse(s).to_string(), s);
+        }
+        assert_eq!(Answer::parse("+5"), Answer::Text("+5".into()));
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::solutions::{AocError, SharedSolver};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        if samples.is_empty() {
+            return Stats {
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                mean: Duration::ZERO,
+                p95: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = samples[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+        let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.3} ms | median {:.3} ms | mean {:.3} ms | p95 {:.3} ms | stddev {:.3} ms",
+            ms(self.min),
+            ms(self.median),
+            ms(self.mean),
+            ms(self.p95),
+            ms(self.stddev)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub part1: bool,
+    pub iterations: usize,
+    pub parsing: Stats,
+    pub compute: Stats,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Part {} ({} iterations)",
+            if self.part1 { 1 } else { 2 },
+            self.iterations
+        )?;
+        writeln!(f, "  Parsing: {}", self.parsing)?;
+        write!(f, "  Compute: {}", self.compute)
+    }
+}
+
+pub fn bench<S: SharedSolver>(
+    input: &str,
+    part1: bool,
+    opts: &BenchOptions,
+) -> Result<BenchReport, AocError> {
+    for _ in 0..opts.warmup {
+        let i = S::parse(input)?;
+        S::solve_part(input, &i, &S::analyze(&i)?, part1)?;
+    }
+
+    let mut parsing = Vec::with_capacity(opts.iterations);
+    let mut compute = Vec::with_capacity(opts.iterations);
+    for _ in 0..opts.iterations {
+        let start = Instant::now();
+        let i = S::parse(input)?;
+        let parsing_end = Instant::now();
+        let shared = S::analyze(&i)?;
+        std::hint::black_box(S::solve_part(input, &i, &shared, part1)?);
+        let end = Instant::now();
+
+        parsing.push(parsing_end - start);
+        compute.push(end - parsing_end);
+    }
+
+    Ok(BenchReport {
+        part1,
+        iterations: opts.iterations,
+        parsing: Stats::from_samples(parsing),
+        compute: Stats::from_samples(compute),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=20).map(Duration::from_millis).collect();
+        let s = Stats::from_samples(samples);
+        assert_eq!(s.min, Duration::from_millis(1));
+        assert_eq!(s.median, Duration::from_micros(10500));
+        assert!((s.mean.as_secs_f64() * 1000.0 - 10.5).abs() < 0.001);
+        assert_eq!(s.p95, Duration::from_millis(19));
+        assert!((s.stddev.as_secs_f64() * 1000.0 - 5.766).abs() < 0.001);
+    }
+
+    #[test]
+    fn stats_single() {
+        let s = Stats::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!(s.min, s.p95);
+        assert_eq!(s.median, Duration::from_millis(3));
+        assert_eq!(s.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,104 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use crate::solutions::{metrics, AocError};
+
+// Cancellation token of a run, cancelled once its deadline is passed.
+#[derive(Debug, Clone)]
+pub struct Token {
+    start: Instant,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    pub fn new(timeout: Option<Duration>) -> Self {
+        let start = Instant::now();
+        Token {
+            start,
+            deadline: timeout.map(|t| start + t),
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+// Puts the previous token back when dropped, even if the solver panicked.
+struct Restore(Option<Token>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        ACTIVE.with(|a| a.replace(prev));
+    }
+}
+
+// Runs `f` with `token` as the token polled by `check`.
+pub fn run<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(ACTIVE.with(|a| a.replace(Some(token.clone()))));
+    f()
+}
+
+// Polled by solvers in their hot loops: fails once the run is cancelled, with
+// the counters bumped so far as the progress made. Always succeeds outside of
+// `run`.
+pub fn check() -> Result<(), AocError> {
+    let elapsed = ACTIVE.with(|a| {
+        a.borrow()
+            .as_ref()
+            .filter(|t| t.is_cancelled())
+            .map(|t| t.start.elapsed())
+    });
+    match elapsed {
+        None => Ok(()),
+        Some(elapsed) => Err(AocError::Cancelled {
+            elapsed,
+            progress: metrics::counters()
+                .iter()
+                .map(|(name, n)| format!("{}={}", name, n))
+                .join(", "),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check, run, Token};
+    use crate::solutions::{metrics, AocError};
+
+    #[test]
+    fn cancels() {
+        assert!(check().is_ok());
+
+        assert!(run(&Token::new(None), check).is_ok());
+        assert!(run(&Token::new(Some(Duration::ZERO)), check).is_err());
+        assert!(check().is_ok());
+
+        let expired = Token::new(Some(Duration::ZERO));
+        let panicked = std::panic::catch_unwind(|| run(&expired, || panic!("solver bug")));
+        assert!(panicked.is_err());
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn times_out() {
+        let token = Token::new(Some(Duration::ZERO));
+        let (res, _) = metrics::collect(|| {
+            metrics::count("presses", 3);
+            run(&token, check)
+        });
+        match res {
+            Err(AocError::Cancelled { progress, .. }) => assert_eq!(progress, "presses=3"),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+}
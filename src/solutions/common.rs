@@ -129,17 +129,15 @@ impl<T> Map<T> {
         )
     }
 }
-impl<T: Display> Map<T> {
-    pub fn print_map(&self) {
-        let (rows, cols) = self.shape();
-        println!("----");
-        for i in 0..rows {
-            for j in 0..cols {
-                print!("{}", self.0[i][j]);
+impl<T: Display> Display for Map<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.0.iter() {
+            for cell in row {
+                write!(f, "{}", cell)?;
             }
-            println!("");
+            writeln!(f)?;
         }
-        println!("----");
+        Ok(())
     }
 }
 #[derive(Clone)]
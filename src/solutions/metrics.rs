@@ -0,0 +1,267 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+pub const PARSE: &str = "parse";
+pub const ANALYZE: &str = "analyze";
+pub const COMPUTE: &str = "compute";
+
+// What was measured while solving: the time of each phase, the counters
+// bumped by the solver and, with the `count-alloc` feature, the peak heap
+// usage above what was allocated before. Phases timed within another one,
+// like those of a solver within `COMPUTE`, are kept apart in `nested` so
+// that they are not counted twice.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metrics {
+    pub phases: Vec<(&'static str, Duration)>,
+    pub nested: Vec<(&'static str, Duration)>,
+    pub counters: BTreeMap<&'static str, u64>,
+    pub peak_heap: Option<usize>,
+}
+
+impl Metrics {
+    pub fn phase(&self, name: &str) -> Duration {
+        self.phases
+            .iter()
+            .chain(self.nested.iter())
+            .filter(|(n, _)| *n == name)
+            .map(|(_, d)| *d)
+            .sum()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|(_, d)| *d).sum()
+    }
+
+    // Adds what was measured by `other` before this.
+    pub fn prepend(&mut self, other: Metrics) {
+        let phases = std::mem::replace(&mut self.phases, other.phases);
+        self.phases.extend(phases);
+        let nested = std::mem::replace(&mut self.nested, other.nested);
+        self.nested.extend(nested);
+        for (name, n) in other.counters {
+            *self.counters.entry(name).or_default() += n;
+        }
+        self.peak_heap = match (self.peak_heap, other.peak_heap) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Metrics>> = const { RefCell::new(None) };
+    // Phases being timed
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+// Runs `f` and returns what it measured through `time` and `count`.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Metrics) {
+    let prev = ACTIVE.with(|a| a.replace(Some(Metrics::default())));
+    let depth = DEPTH.with(|d| d.replace(0));
+    let baseline = alloc::reset_peak();
+    let res = f();
+    let peak = alloc::peak_since(baseline);
+    DEPTH.with(|d| d.set(depth));
+    let mut metrics = ACTIVE.with(|a| a.replace(prev)).unwrap_or_default();
+    metrics.peak_heap = peak;
+    (res, metrics)
+}
+
+// Times `f` as `phase`. Solvers can use it for their own phases.
+pub fn time<T>(phase: &'static str, f: impl FnOnce() -> T) -> T {
+    let depth = DEPTH.with(|d| d.replace(d.get() + 1));
+    let start = Instant::now();
+    let res = f();
+    let elapsed = start.elapsed();
+    DEPTH.with(|d| d.set(depth));
+    ACTIVE.with(|a| {
+        if let Some(m) = a.borrow_mut().as_mut() {
+            if depth == 0 {
+                m.phases.push((phase, elapsed));
+            } else {
+                m.nested.push((phase, elapsed));
+            }
+        }
+    });
+    res
+}
+
+// Adds `n` to a custom counter, like the states explored by a search. Does
+// nothing outside of `collect`.
+pub fn count(name: &'static str, n: u64) {
+    ACTIVE.with(|a| {
+        if let Some(m) = a.borrow_mut().as_mut() {
+            *m.counters.entry(name).or_default() += n;
+        }
+    });
+}
+
+// Bumps of a counter in a hot loop, added to it once every `every` bumps and
+// when dropped, so that the loop does not pay for `count` each time.
+pub struct Counter {
+    name: &'static str,
+    every: u64,
+    pending: u64,
+}
+
+impl Counter {
+    pub fn new(name: &'static str, every: u64) -> Self {
+        Counter {
+            name,
+            every: every.max(1),
+            pending: 0,
+        }
+    }
+
+    // Returns whether the bumps were just added, the time for a loop to poll
+    // `cancel::check` too.
+    pub fn inc(&mut self) -> bool {
+        self.pending += 1;
+        if self.pending < self.every {
+            return false;
+        }
+        count(self.name, std::mem::take(&mut self.pending));
+        true
+    }
+}
+
+impl Drop for Counter {
+    fn drop(&mut self) {
+        if self.pending > 0 {
+            count(self.name, self.pending);
+        }
+    }
+}
+
+// Counters bumped so far in the current `collect`.
+pub fn counters() -> BTreeMap<&'static str, u64> {
+    ACTIVE.with(|a| {
+        a.borrow()
+            .as_ref()
+            .map(|m| m.counters.clone())
+            .unwrap_or_default()
+    })
+}
+
+#[cfg(feature = "count-alloc")]
+pub mod alloc {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    // System allocator keeping track of the live and peak heap size, installed
+    // as the global allocator when the `count-alloc` feature is enabled.
+    pub struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+                PEAK.fetch_max(current, Ordering::Relaxed);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+    }
+
+    pub(super) fn reset_peak() -> usize {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        current
+    }
+
+    pub(super) fn peak_since(baseline: usize) -> Option<usize> {
+        Some(PEAK.load(Ordering::Relaxed).saturating_sub(baseline))
+    }
+}
+
+#[cfg(not(feature = "count-alloc"))]
+mod alloc {
+    pub(super) fn reset_peak() -> usize {
+        0
+    }
+
+    pub(super) fn peak_since(_: usize) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{collect, count, time, Counter, Metrics, COMPUTE, PARSE};
+
+    #[test]
+    fn collects() {
+        count("outside", 1);
+        let (res, m) = collect(|| {
+            let a = time(PARSE, || 20);
+            count("states", 2);
+            let (_, inner) = collect(|| count("states", 5));
+            assert_eq!(inner.counters["states"], 5);
+            time(COMPUTE, || count("states", 1));
+            a + 1
+        });
+        assert_eq!(res, 21);
+        assert_eq!(
+            m.phases.iter().map(|p| p.0).collect::<Vec<_>>(),
+            [PARSE, COMPUTE]
+        );
+        assert_eq!(m.counters.len(), 1);
+        assert_eq!(m.counters["states"], 3);
+    }
+
+    #[test]
+    fn nested_phases() {
+        let (_, m) = collect(|| {
+            time(COMPUTE, || {
+                time("search", || std::thread::sleep(Duration::from_millis(2)))
+            })
+        });
+        assert_eq!(m.phases.len(), 1);
+        assert_eq!(m.nested[0].0, "search");
+        assert_eq!(m.total(), m.phase(COMPUTE));
+        assert!(m.phase("search") <= m.total());
+    }
+
+    #[test]
+    fn counts_in_batches() {
+        let (flushed, m) = collect(|| {
+            let mut c = Counter::new("states", 4);
+            let flushed = (0..10).filter(|_| c.inc()).count();
+            assert_eq!(super::counters()["states"], 8);
+            flushed
+        });
+        assert_eq!(flushed, 2);
+        assert_eq!(m.counters["states"], 10);
+    }
+
+    #[test]
+    fn prepend() {
+        let mut m = Metrics {
+            phases: vec![(COMPUTE, Duration::from_millis(3))],
+            counters: [("states", 1)].into(),
+            ..Metrics::default()
+        };
+        m.prepend(Metrics {
+            phases: vec![(PARSE, Duration::from_millis(1))],
+            counters: [("states", 2), ("other", 1)].into(),
+            peak_heap: Some(10),
+            ..Metrics::default()
+        });
+        assert_eq!(m.phase(PARSE), Duration::from_millis(1));
+        assert_eq!(m.total(), Duration::from_millis(4));
+        assert_eq!(m.phases[0].0, PARSE);
+        assert_eq!(m.counters["states"], 3);
+        assert_eq!(m.peak_heap, Some(10));
+    }
+}
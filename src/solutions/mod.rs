@@ -1,48 +1,290 @@
 #![feature(array_windows)]
+use std::cell::RefCell;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::time::Instant;
 
+use answer::{Answer, IntoAnswer};
+use metrics::Metrics;
+
+pub mod answer;
+pub mod bench;
+pub mod cancel;
 mod common;
+pub mod metrics;
+pub mod progress;
+pub mod registry;
 mod template;
-mod y2023;
+#[cfg(test)]
+mod test_support;
+
+registry::years! {
+    y2018,
+    y2019,
+    y2021,
+    y2023,
+}
+
+pub use y2019::intcode;
 
 #[derive(Debug, Error)]
 pub enum AocError {
-    #[error("parse eror")]
+    #[error("parse error")]
     ParseIntError {
         #[from]
         source: std::num::ParseIntError,
     },
+    #[error("{year} day {day} is not implemented")]
+    NotImplemented { year: u16, day: u8 },
+    #[error("{year} day {day} part {part} is not implemented")]
+    PartNotImplemented { year: u16, day: u8, part: u8 },
+    // `snippet` is the whole input line, `column` counts chars from 1.
+    #[error("line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+    #[error("{year} day {day}{}: {source}", part.map(|p| format!(" part {}", p)).unwrap_or_default())]
+    InDay {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+        source: Box<AocError>,
+    },
+    // `progress` lists the counters of the solver when it was cancelled.
+    #[error("cancelled after {elapsed:?}{}", if progress.is_empty() { String::new() } else { format!(" ({})", progress) })]
+    Cancelled { elapsed: Duration, progress: String },
     #[error("unknown error {0}")]
     Unknown(String),
 }
 
+impl AocError {
+    // Parse error located at `at`, a slice of `input` such as one of its lines
+    // or the remaining input of a nom parser.
+    pub fn parse(input: &str, at: &str, message: impl Into<String>) -> AocError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o + at.len() <= input.len())
+            .or_else(|| input.find(at))
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        AocError::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[line_start..].lines().next().unwrap_or("").to_owned(),
+            message: message.into(),
+        }
+    }
+
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> AocError {
+        match err {
+            nom::Err::Incomplete(_) => {
+                AocError::parse(input, &input[input.len()..], "unexpected end of input")
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => AocError::parse(
+                input,
+                e.input,
+                format!("unexpected input ({})", e.code.description().to_lowercase()),
+            ),
+        }
+    }
+
+    // Attaches the day being solved, unless the error already names it.
+    pub fn in_day(self, year: u16, day: u8, part: Option<u8>) -> AocError {
+        match self {
+            e @ (AocError::NotImplemented { .. }
+            | AocError::PartNotImplemented { .. }
+            | AocError::InDay { .. }) => e,
+            e => AocError::InDay {
+                year,
+                day,
+                part,
+                source: Box::new(e),
+            },
+        }
+    }
+
+    // Input line of a parse error with a caret under the offending column.
+    pub fn excerpt(&self) -> Option<String> {
+        match self {
+            AocError::InDay { source, .. } => source.excerpt(),
+            AocError::Parse {
+                line,
+                column,
+                snippet,
+                ..
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                Some(format!(
+                    "{} |\n{} | {}\n{} | {}^",
+                    gutter,
+                    line,
+                    snippet,
+                    gutter,
+                    " ".repeat(column - 1)
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Answer,
+    pub metrics: Metrics,
+}
+
+impl Solved {
+    pub fn total(&self) -> Duration {
+        self.metrics.total()
+    }
+
+    pub fn parsing(&self) -> Duration {
+        self.metrics.phase(metrics::PARSE)
+    }
+
+    // Everything but the parsing, including the analysis and solver phases.
+    pub fn compute(&self) -> Duration {
+        self.total() - self.parsing()
+    }
+}
+
 pub trait Solver {
     type Input;
-    type Output: std::fmt::Display;
+    type Output1: IntoAnswer;
+    type Output2: IntoAnswer;
 
     fn parse_input(_: &str) -> Result<Self::Input, AocError>;
-    fn solve_part1(_: Self::Input) -> Result<Self::Output, AocError>;
-    fn solve_part2(_: Self::Input) -> Result<Self::Output, AocError>;
-
-    fn solve(input: &str, part1: bool) -> Result<String, AocError> {
-        let start = Instant::now();
-        let i = Self::parse_input(input)?;
-        let parsing_end = Instant::now();
-        let res = if part1 {
-            Self::solve_part1(i)?
-        } else {
-            Self::solve_part2(i)?
-        };
-        let end = Instant::now();
-
-        println!("Run time: {} ms", (end - start).as_secs_f32() * 1000.0);
-        println!(
-            "Parsing: {} ms",
-            (parsing_end - start).as_secs_f32() * 1000.0
-        );
-        println!("Compute: {} ms", (end - parsing_end).as_secs_f32() * 1000.0);
-        Ok(res.to_string())
+    fn solve_part1(_: Self::Input) -> Result<Self::Output1, AocError>;
+    fn solve_part2(_: Self::Input) -> Result<Self::Output2, AocError>;
+}
+
+// Variant of `Solver` where both parts borrow the parsed input, along with a
+// `Shared` analysis computed once from it. Days without shared work can use
+// `type Shared = ()`.
+pub trait SharedSolver {
+    type Input;
+    type Shared;
+    type Output1: IntoAnswer;
+    type Output2: IntoAnswer;
+
+    fn parse(_: &str) -> Result<Self::Input, AocError>;
+    fn analyze(_: &Self::Input) -> Result<Self::Shared, AocError>;
+    fn part1(_: &Self::Input, _: &Self::Shared) -> Result<Self::Output1, AocError>;
+    fn part2(_: &Self::Input, _: &Self::Shared) -> Result<Self::Output2, AocError>;
+
+    // Solves a part, timed as computation. `raw` is the input `input` was
+    // parsed from.
+    fn solve_part(
+        _raw: &str,
+        input: &Self::Input,
+        shared: &Self::Shared,
+        part1: bool,
+    ) -> Result<Answer, AocError> {
+        metrics::time(metrics::COMPUTE, || {
+            if part1 {
+                Self::part1(input, shared).map(IntoAnswer::into_answer)
+            } else {
+                Self::part2(input, shared).map(IntoAnswer::into_answer)
+            }
+        })
+    }
+
+    fn prepare(input: &str) -> Result<(Self::Input, Self::Shared), AocError> {
+        let i = metrics::time(metrics::PARSE, || Self::parse(input))?;
+        let shared = metrics::time(metrics::ANALYZE, || Self::analyze(&i))?;
+        Ok((i, shared))
+    }
+
+    fn solve_timed(input: &str, part1: bool) -> Result<Solved, AocError> {
+        let (answer, metrics) = metrics::collect(|| {
+            let (i, shared) = Self::prepare(input)?;
+            Self::solve_part(input, &i, &shared, part1)
+        });
+        Ok(Solved {
+            answer: answer?,
+            metrics,
+        })
+    }
+
+    // Solves several parts from a single parse and analysis, reporting each
+    // part as soon as it is done. The first part carries the metrics of the
+    // parse and the analysis. Errors of the parse or the analysis are returned
+    // instead, without reporting any part.
+    fn solve_parts(
+        input: &str,
+        parts: &[u8],
+        report: &mut dyn FnMut(u8, Result<Solved, AocError>),
+    ) -> Result<(), AocError> {
+        let (prepared, setup) = metrics::collect(|| Self::prepare(input));
+        let (i, shared) = prepared?;
+        let mut setup = Some(setup);
+        for &part in parts {
+            let (res, mut metrics) =
+                metrics::collect(|| Self::solve_part(input, &i, &shared, part == 1));
+            if let Some(setup) = setup.take() {
+                metrics.prepend(setup);
+            }
+            report(part, res.map(|answer| Solved { answer, metrics }));
+        }
+        Ok(())
+    }
+}
+
+// Input of a `Solver` running as a `SharedSolver`. `Solver` parts consume
+// their input, so the first part to run takes the parsed one, and
+// `solve_part` parses the input again for the next, timed as parsing.
+pub struct Reparse<I> {
+    parsed: RefCell<Option<I>>,
+}
+
+impl<I> Reparse<I> {
+    fn take(&self) -> Result<I, AocError> {
+        self.parsed
+            .take()
+            .ok_or_else(|| AocError::Unknown("input already consumed".into()))
+    }
+}
+
+impl<S: Solver> SharedSolver for S {
+    type Input = Reparse<S::Input>;
+    type Shared = ();
+    type Output1 = S::Output1;
+    type Output2 = S::Output2;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(Reparse {
+            parsed: RefCell::new(Some(S::parse_input(input)?)),
+        })
+    }
+
+    fn analyze(_: &Self::Input) -> Result<(), AocError> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input, _: &()) -> Result<Self::Output1, AocError> {
+        S::solve_part1(input.take()?)
+    }
+
+    fn part2(input: &Self::Input, _: &()) -> Result<Self::Output2, AocError> {
+        S::solve_part2(input.take()?)
+    }
+
+    fn solve_part(raw: &str, input: &Self::Input, _: &(), part1: bool) -> Result<Answer, AocError> {
+        if input.parsed.borrow().is_none() {
+            let parsed = metrics::time(metrics::PARSE, || S::parse_input(raw))?;
+            input.parsed.replace(Some(parsed));
+        }
+        metrics::time(metrics::COMPUTE, || {
+            if part1 {
+                Self::part1(input, &()).map(IntoAnswer::into_answer)
+            } else {
+                Self::part2(input, &()).map(IntoAnswer::into_answer)
+            }
+        })
     }
 }
 
@@ -63,33 +305,84 @@ impl InputDay {
     }
 }
 
-pub fn run(year: &str, day: &str, part1: bool, input: &str) -> Result<String, AocError> {
-    match (year, day) {
-        ("2023", "1") => y2023::d1::Solution::solve(input, part1),
-        ("2023", "2") => y2023::d2::Solution::solve(input, part1),
-        ("2023", "3") => y2023::d3::Solution::solve(input, part1),
-        ("2023", "4") => y2023::d4::Solution::solve(input, part1),
-        ("2023", "5") => y2023::d5::Solution::solve(input, part1),
-        ("2023", "6") => y2023::d6::Solution::solve(input, part1),
-        ("2023", "7") => y2023::d7::Solution::solve(input, part1),
-        ("2023", "8") => y2023::d8::Solution::solve(input, part1),
-        ("2023", "9") => y2023::d9::Solution::solve(input, part1),
-        ("2023", "10") => y2023::d10::Solution::solve(input, part1),
-        ("2023", "11") => y2023::d11::Solution::solve(input, part1),
-        ("2023", "12") => y2023::d12::Solution::solve(input, part1),
-        ("2023", "13") => y2023::d13::Solution::solve(input, part1),
-        ("2023", "14") => y2023::d14::Solution::solve(input, part1),
-        ("2023", "15") => y2023::d15::Solution::solve(input, part1),
-        ("2023", "16") => y2023::d16::Solution::solve(input, part1),
-        ("2023", "17") => y2023::d17::Solution::solve(input, part1),
-        ("2023", "18") => y2023::d18::Solution::solve(input, part1),
-        ("2023", "19") => y2023::d19::Solution::solve(input, part1),
-        ("2023", "20") => y2023::d20::Solution::solve(input, part1),
-        ("2023", "21") => y2023::d21::Solution::solve(input, part1),
-        ("2023", "22") => y2023::d22::Solution::solve(input, part1),
-        ("2023", "23") => y2023::d23::Solution::solve(input, part1),
-        ("2023", "24") => y2023::d24::Solution::solve(input, part1),
-        ("2023", "25") => y2023::d25::Solution::solve(input, part1),
-        _ => unimplemented!(),
+#[cfg(test)]
+mod tests {
+    use super::{metrics, AocError, SharedSolver, Solver};
+
+    #[test]
+    fn parse_location() {
+        let input = "a -> b\n%c - d\n";
+        let line = input.lines().nth(1).unwrap();
+        let e = AocError::parse(input, &line[3..], "expected `->`").in_day(2023, 20, Some(1));
+        assert_eq!(
+            e.to_string(),
+            "2023 day 20 part 1: line 2, column 4: expected `->`"
+        );
+        assert_eq!(e.excerpt().unwrap(), "  |\n2 | %c - d\n  |    ^");
+
+        let e = AocError::parse(input, &input[..0], "empty").in_day(2023, 20, None);
+        assert_eq!(e.to_string(), "2023 day 20: line 1, column 1: empty");
+    }
+
+    #[test]
+    fn nom_errors() {
+        use nom::character::complete::digit1;
+
+        let input = "12\n3x";
+        let rest = &input[3..];
+        let err = nom::combinator::all_consuming(digit1::<&str, nom::error::Error<&str>>)(rest)
+            .unwrap_err();
+        match AocError::from_nom(input, err) {
+            AocError::Parse {
+                line,
+                column,
+                snippet,
+                ..
+            } => assert_eq!((line, column, snippet.as_str()), (2, 2, "3x")),
+            e => panic!("unexpected {:?}", e),
+        }
+    }
+
+    enum Sum {}
+
+    impl Solver for Sum {
+        type Input = Vec<i64>;
+        type Output1 = i64;
+        type Output2 = i64;
+
+        fn parse_input(input: &str) -> Result<Self::Input, AocError> {
+            input.split(',').map(|n| Ok(n.parse()?)).collect()
+        }
+
+        fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+            Ok(input.iter().sum())
+        }
+
+        fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn reparse() {
+        let mut solved = vec![];
+        Sum::solve_parts("2,3,4", &[1, 2], &mut |part, res| {
+            solved.push((part, res.unwrap()))
+        })
+        .unwrap();
+        let answers: Vec<_> = solved.iter().map(|(_, s)| s.answer.to_string()).collect();
+        assert_eq!(answers, ["9", "24"]);
+        // The second part parses again, and says so
+        let phases: Vec<Vec<_>> = solved
+            .iter()
+            .map(|(_, s)| s.metrics.phases.iter().map(|(p, _)| *p).collect())
+            .collect();
+        assert_eq!(
+            phases,
+            [
+                vec![metrics::PARSE, metrics::ANALYZE, metrics::COMPUTE],
+                vec![metrics::PARSE, metrics::COMPUTE],
+            ]
+        );
     }
 }
@@ -0,0 +1,125 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// Off unless the CLI draws on a terminal, so tests and piped runs stay quiet.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const REDRAW: Duration = Duration::from_millis(100);
+const WIDTH: usize = 30;
+
+pub fn enable(on: bool) {
+    ENABLED.store(on, Ordering::Relaxed);
+}
+
+// Progress of a long search, drawn on stderr as a bar when the total is
+// known and as a counter otherwise. Redraws are throttled, and the line is
+// cleared once the handle is dropped.
+pub struct Progress {
+    label: &'static str,
+    total: Option<u64>,
+    done: u64,
+    best: Option<String>,
+    enabled: bool,
+    drawn: Option<Instant>,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: Option<u64>) -> Self {
+        Progress {
+            label,
+            total,
+            done: 0,
+            best: None,
+            enabled: ENABLED.load(Ordering::Relaxed),
+            drawn: None,
+        }
+    }
+
+    pub fn inc(&mut self, n: u64) {
+        self.done += n;
+        self.tick();
+    }
+
+    // Best result so far, shown next to the count.
+    pub fn best(&mut self, best: impl Display) {
+        if self.enabled {
+            self.best = Some(best.to_string());
+            self.tick();
+        }
+    }
+
+    fn tick(&mut self) {
+        if !self.enabled || self.drawn.is_some_and(|t| t.elapsed() < REDRAW) {
+            return;
+        }
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(stderr, "\r{}{}", termion::clear::CurrentLine, self.render());
+        let _ = stderr.flush();
+        self.drawn = Some(Instant::now());
+    }
+
+    fn render(&self) -> String {
+        let mut line = match self.total {
+            Some(total) => {
+                let filled = (self.done.min(total) * WIDTH as u64)
+                    .checked_div(total)
+                    .unwrap_or(WIDTH as u64) as usize;
+                format!(
+                    "{} [{}{}] {}/{}",
+                    self.label,
+                    "#".repeat(filled),
+                    "-".repeat(WIDTH - filled),
+                    self.done,
+                    total
+                )
+            }
+            None => format!("{} {}", self.label, self.done),
+        };
+        if let Some(best) = &self.best {
+            line += &format!(" (best {})", best);
+        }
+        line
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.drawn.is_some() {
+            eprint!("\r{}", termion::clear::CurrentLine);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Progress;
+
+    #[test]
+    fn render() {
+        let mut p = Progress::new("lines", Some(4));
+        p.inc(1);
+        assert_eq!(
+            p.render(),
+            format!("lines [{}{}] 1/4", "#".repeat(7), "-".repeat(23))
+        );
+        p.inc(3);
+        p.best = Some("12".into());
+        assert_eq!(
+            p.render(),
+            format!("lines [{}] 4/4 (best 12)", "#".repeat(30))
+        );
+
+        let mut p = Progress::new("paths", None);
+        p.inc(42);
+        assert_eq!(p.render(), "paths 42");
+        assert_eq!(
+            Progress::new("empty", Some(0))
+                .render()
+                .matches('#')
+                .count(),
+            30
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use crate::solutions::bench::{self, BenchOptions, BenchReport};
+use crate::solutions::{self, AocError, SharedSolver, Solved};
+
+// Declares the day modules of a year along with their title and implemented
+// parts, and generates the `register` function for that year.
+macro_rules! days {
+    ($year:literal; $($day:literal => $m:ident, $title:literal, [$($part:literal),*];)*) => {
+        $(pub mod $m;)*
+
+        pub fn register(registry: &mut $crate::solutions::registry::Registry) {
+            $(
+                registry.register($crate::solutions::registry::Day::new::<$m::Solution>(
+                    $year,
+                    $day,
+                    $title,
+                    &[$($part),*],
+                ));
+            )*
+        }
+    };
+}
+pub(crate) use days;
+
+// Declares the year modules and generates `register_years`.
+macro_rules! years {
+    ($($m:ident,)*) => {
+        $(mod $m;)*
+
+        pub fn register_years(registry: &mut $crate::solutions::registry::Registry) {
+            $($m::register(registry);)*
+        }
+    };
+}
+pub(crate) use years;
+
+type SolveFn = fn(&str, bool) -> Result<Solved, AocError>;
+type SolvePartsFn =
+    fn(&str, &[u8], &mut dyn FnMut(u8, Result<Solved, AocError>)) -> Result<(), AocError>;
+type BenchFn = fn(&str, bool, &BenchOptions) -> Result<BenchReport, AocError>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: &'static [u8],
+    solve: SolveFn,
+    solve_parts: SolvePartsFn,
+    bench: BenchFn,
+}
+
+impl Day {
+    pub fn new<S: SharedSolver>(
+        year: u16,
+        day: u8,
+        title: &'static str,
+        parts: &'static [u8],
+    ) -> Day {
+        Day {
+            year,
+            day,
+            title,
+            parts,
+            solve: S::solve_timed,
+            solve_parts: S::solve_parts,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    pub fn has_part(&self, part1: bool) -> bool {
+        self.parts.contains(&if part1 { 1 } else { 2 })
+    }
+
+    pub fn check_part(&self, part1: bool) -> Result<(), AocError> {
+        if self.has_part(part1) {
+            Ok(())
+        } else {
+            Err(AocError::PartNotImplemented {
+                year: self.year,
+                day: self.day,
+                part: if part1 { 1 } else { 2 },
+            })
+        }
+    }
+
+    pub fn solve(&self, input: &str, part1: bool) -> Result<Solved, AocError> {
+        self.check_part(part1)?;
+        (self.solve)(input, part1)
+            .map_err(|e| e.in_day(self.year, self.day, Some(if part1 { 1 } else { 2 })))
+    }
+
+    // Solves every implemented part from a single parse.
+    pub fn solve_all(
+        &self,
+        input: &str,
+        report: &mut dyn FnMut(u8, Result<Solved, AocError>),
+    ) -> Result<(), AocError> {
+        (self.solve_parts)(input, self.parts, &mut |part, res| {
+            report(
+                part,
+                res.map_err(|e| e.in_day(self.year, self.day, Some(part))),
+            )
+        })
+        .map_err(|e| e.in_day(self.year, self.day, None))
+    }
+
+    pub fn bench(
+        &self,
+        input: &str,
+        part1: bool,
+        opts: &BenchOptions,
+    ) -> Result<BenchReport, AocError> {
+        self.check_part(part1)?;
+        (self.bench)(input, part1, opts)
+            .map_err(|e| e.in_day(self.year, self.day, Some(if part1 { 1 } else { 2 })))
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), Day>,
+}
+
+impl Registry {
+    pub fn register(&mut self, day: Day) {
+        if let Some(prev) = self.days.insert((day.year, day.day), day) {
+            panic!("{} day {} registered twice", prev.year, prev.day);
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<&Day, AocError> {
+        self.days
+            .get(&(year, day))
+            .ok_or(AocError::NotImplemented { year, day })
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Day> {
+        self.days.range((year, 0)..=(year, u8::MAX)).map(|(_, d)| d)
+    }
+
+    pub fn select(&self, year: Option<u16>, day: Option<u8>) -> Result<Vec<&Day>, AocError> {
+        match (year, day) {
+            (Some(year), Some(day)) => Ok(vec![self.get(year, day)?]),
+            (Some(year), None) => Ok(self.year(year).collect()),
+            (None, _) => Ok(self.days().collect()),
+        }
+    }
+}
+
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry = Registry::default();
+        solutions::register_years(&mut registry);
+        registry
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::registry;
+    use crate::solutions::AocError;
+
+    #[test]
+    fn lookup() {
+        let r = registry();
+        let d = r.get(2023, 1).unwrap();
+        assert_eq!(d.title, "Trebuchet?!");
+        assert!(d.has_part(true) && d.has_part(false));
+        assert!(matches!(
+            r.get(2023, 26),
+            Err(AocError::NotImplemented {
+                year: 2023,
+                day: 26
+            })
+        ));
+        assert!(matches!(
+            r.get(2023, 25).unwrap().solve("", false),
+            Err(AocError::PartNotImplemented { part: 2, .. })
+        ));
+        assert_eq!(r.year(2023).count(), 25);
+        assert!(r.year(2023).zip(1..).all(|(d, i)| d.day == i));
+    }
+}
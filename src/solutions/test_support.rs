@@ -0,0 +1,44 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use crate::solutions::SharedSolver;
+
+// Checks a day against an example: the input must parse, and each given part
+// must render to the expected answer. Timings are logged at the debug level.
+//
+//     example_test!(Solution, input, part1 = 4361, part2 = "467835");
+macro_rules! example_test {
+    ($solver:ty, $input:expr $(, part1 = $p1:expr)? $(, part2 = $p2:expr)? $(,)?) => {{
+        let input = $input;
+        $crate::solutions::test_support::check_parse::<$solver>(input);
+        $($crate::solutions::test_support::check_part::<$solver>(input, 1, $p1);)?
+        $($crate::solutions::test_support::check_part::<$solver>(input, 2, $p2);)?
+    }};
+}
+pub(crate) use example_test;
+
+pub fn check_parse<S: SharedSolver>(input: &str) {
+    let start = Instant::now();
+    if let Err(e) = S::parse(input) {
+        panic!("parsing failed: {}", e);
+    }
+    log::debug!("parsed in {:?}", start.elapsed());
+}
+
+pub fn check_part<S: SharedSolver>(input: &str, part: u8, expected: impl Display) {
+    let parsed = S::parse(input).unwrap();
+    let start = Instant::now();
+    let res =
+        S::analyze(&parsed).and_then(|shared| S::solve_part(input, &parsed, &shared, part == 1));
+    let elapsed = start.elapsed();
+    let answer = res
+        .unwrap_or_else(|e| panic!("part {} failed: {}", part, e))
+        .to_string();
+    log::debug!("part {} solved in {:?}", part, elapsed);
+    assert_eq!(
+        answer,
+        expected.to_string(),
+        "part {} returned a wrong answer",
+        part
+    );
+}
@@ -0,0 +1,6 @@
+use crate::solutions::registry::days;
+
+days! {
+    2018;
+    1 => r2018_1, "Chronal Calibration", [1, 2];
+}
@@ -1,23 +1,23 @@
-use failure::Error;
-use crate::solutions::Solver;
+use crate::solutions::{AocError, Solver};
 
 pub enum Solution {}
 
 impl Solver for Solution {
     type Input = Vec<i32>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines()
             .map(|l|l.parse().map_err(From::from))
             .collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         Ok(input.iter().sum())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut hash_set = std::collections::HashSet::new();
         let mut running = 0;
 
@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+
+use super::{Instruction, Mode, Opcode, Program};
+use crate::solutions::AocError;
+
+// A number, a label, or a label with an offset like `table+2`.
+struct Expr<'a> {
+    label: Option<&'a str>,
+    offset: i64,
+}
+
+struct Operand<'a> {
+    mode: Mode,
+    expr: Expr<'a>,
+}
+
+enum Item<'a> {
+    Word(Expr<'a>),
+    Text(String),
+}
+
+enum Statement<'a> {
+    Code(Opcode, Vec<Operand<'a>>),
+    Data(Vec<Item<'a>>),
+    // Moves the relative base to an address
+    Base(Expr<'a>),
+}
+
+impl Statement<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Statement::Code(opcode, _) => 1 + opcode.arity(),
+            Statement::Data(items) => items
+                .iter()
+                .map(|i| match i {
+                    Item::Word(_) => 1,
+                    Item::Text(t) => t.chars().count(),
+                })
+                .sum(),
+            Statement::Base(_) => 2,
+        }
+    }
+}
+
+fn is_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Splits on the commas, and ends at the comment, outside of strings.
+fn split_items(s: &str) -> Vec<&str> {
+    let mut items = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                items.push(&s[start..i]);
+                start = i + 1;
+            }
+            ';' if !quoted => {
+                items.push(&s[start..i]);
+                return items;
+            }
+            _ => {}
+        }
+    }
+    items.push(&s[start..]);
+    items
+}
+
+fn parse_expr<'a>(source: &str, s: &'a str) -> Result<Expr<'a>, AocError> {
+    let mut expr = Expr {
+        label: None,
+        offset: 0,
+    };
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return Err(AocError::parse(source, s, "expected a value"));
+    }
+    while !rest.is_empty() {
+        let (sign, body) = match rest.strip_prefix('-') {
+            Some(body) => (-1, body),
+            None => (1, rest.strip_prefix('+').unwrap_or(rest)),
+        };
+        let end = body.find(['+', '-']).unwrap_or(body.len());
+        let term = body[..end].trim();
+        if let Ok(n) = term.parse::<i64>() {
+            expr.offset += sign * n;
+        } else if is_label(term) && sign == 1 && expr.label.is_none() {
+            expr.label = Some(term);
+        } else {
+            return Err(AocError::parse(
+                source,
+                term,
+                "expected a number or a label",
+            ));
+        }
+        rest = body[end..].trim_start();
+    }
+    Ok(expr)
+}
+
+fn parse_operand<'a>(source: &str, s: &'a str) -> Result<Operand<'a>, AocError> {
+    let s = s.trim();
+    let Some(inner) = s.strip_prefix('[') else {
+        return Ok(Operand {
+            mode: Mode::Immediate,
+            expr: parse_expr(source, s)?,
+        });
+    };
+    let inner = inner
+        .strip_suffix(']')
+        .ok_or_else(|| AocError::parse(source, s, "expected `]`"))?
+        .trim();
+    match inner.strip_prefix("rb") {
+        Some("") => Ok(Operand {
+            mode: Mode::Relative,
+            expr: Expr {
+                label: None,
+                offset: 0,
+            },
+        }),
+        Some(offset) if offset.trim_start().starts_with(['+', '-']) => Ok(Operand {
+            mode: Mode::Relative,
+            expr: parse_expr(source, offset)?,
+        }),
+        _ => Ok(Operand {
+            mode: Mode::Position,
+            expr: parse_expr(source, inner)?,
+        }),
+    }
+}
+
+pub(super) fn parse_text(source: &str, s: &str) -> Result<String, AocError> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| AocError::parse(source, s, "unterminated string"))?;
+    let mut text = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        text.push(match c {
+            '\\' => match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some(c @ ('\\' | '"')) => c,
+                _ => return Err(AocError::parse(source, s, "invalid escape")),
+            },
+            c => c,
+        });
+    }
+    Ok(text)
+}
+
+// Label and statement of a line, both optional.
+fn parse_line<'a>(
+    source: &str,
+    line: &'a str,
+) -> Result<(Option<&'a str>, Option<Statement<'a>>), AocError> {
+    let mut rest = line.trim_start();
+    let mut label = None;
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    if is_label(&rest[..end]) && rest[end..].starts_with(':') {
+        label = Some(&rest[..end]);
+        rest = rest[end + 1..].trim_start();
+    }
+    if rest.is_empty() || rest.starts_with(';') {
+        return Ok((label, None));
+    }
+    let (keyword, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let args = split_items(args);
+    let args = match args.as_slice() {
+        [arg] if arg.trim().is_empty() => vec![],
+        _ => args,
+    };
+    let statement = match keyword {
+        "data" if args.is_empty() => {
+            return Err(AocError::parse(source, keyword, "expected values"));
+        }
+        "data" => Statement::Data(
+            args.iter()
+                .map(|a| match a.trim() {
+                    t if t.starts_with('"') => parse_text(source, t).map(Item::Text),
+                    _ => parse_expr(source, a).map(Item::Word),
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        ".base" => match args.as_slice() {
+            [arg] => Statement::Base(parse_expr(source, arg)?),
+            _ => return Err(AocError::parse(source, keyword, "expected one address")),
+        },
+        mnemonic => {
+            let opcode = Opcode::from_mnemonic(mnemonic)
+                .ok_or_else(|| AocError::parse(source, keyword, "unknown instruction"))?;
+            if args.len() != opcode.arity() {
+                return Err(AocError::parse(
+                    source,
+                    keyword,
+                    format!("expected {} operands", opcode.arity()),
+                ));
+            }
+            let operands = args
+                .iter()
+                .map(|a| parse_operand(source, a))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(i) = opcode.writes() {
+                if operands[i].mode == Mode::Immediate {
+                    return Err(AocError::parse(
+                        source,
+                        args[i].trim(),
+                        "cannot write to an immediate",
+                    ));
+                }
+            }
+            Statement::Code(opcode, operands)
+        }
+    };
+    Ok((label, Some(statement)))
+}
+
+impl Program {
+    // Assembles a program written as the listings of `disassemble`: one
+    // instruction per line, operands as `[addr]`, `[rb+n]` or immediates, any
+    // of which may use labels. `data` lays out numbers and strings, and
+    // `.base ADDR` moves the relative base, assuming nothing else does.
+    pub fn assemble(source: &str) -> Result<Program, AocError> {
+        let mut labels = HashMap::new();
+        let mut statements = vec![];
+        let mut address = 0;
+        for line in source.lines() {
+            let (label, statement) = parse_line(source, line)?;
+            if let Some(label) = label {
+                if labels.insert(label, address).is_some() {
+                    return Err(AocError::parse(source, label, "label defined twice"));
+                }
+            }
+            if let Some(statement) = statement {
+                address += statement.len();
+                statements.push(statement);
+            }
+        }
+
+        let value = |expr: &Expr| match expr.label {
+            None => Ok(expr.offset),
+            Some(label) => labels
+                .get(label)
+                .map(|&a| a as i64 + expr.offset)
+                .ok_or_else(|| AocError::parse(source, label, "unknown label")),
+        };
+        let mut code = Vec::with_capacity(address);
+        let mut base = 0;
+        for statement in &statements {
+            match statement {
+                Statement::Code(opcode, operands) => {
+                    let mut modes = [Mode::Position; 3];
+                    for (mode, operand) in modes.iter_mut().zip(operands) {
+                        *mode = operand.mode;
+                    }
+                    code.push(
+                        Instruction {
+                            opcode: *opcode,
+                            modes,
+                        }
+                        .encode(),
+                    );
+                    for operand in operands {
+                        code.push(value(&operand.expr)?);
+                    }
+                }
+                Statement::Data(items) => {
+                    for item in items {
+                        match item {
+                            Item::Word(expr) => code.push(value(expr)?),
+                            Item::Text(text) => code.extend(text.chars().map(|c| c as i64)),
+                        }
+                    }
+                }
+                Statement::Base(expr) => {
+                    let address = value(expr)?;
+                    code.extend([
+                        Instruction {
+                            opcode: Opcode::AdjustBase,
+                            modes: [Mode::Immediate; 3],
+                        }
+                        .encode(),
+                        address - base,
+                    ]);
+                    base = address;
+                }
+            }
+        }
+        Ok(Program::new(code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solutions::y2019::intcode::Program;
+    use crate::solutions::AocError;
+
+    #[test]
+    fn assembles() {
+        let source = "
+            .base stack          ; past the code
+        loop:
+            in [rb+1]
+            out [rb-1]
+            jnz [rb+1], loop
+            hlt
+        msg: data \"a,;\", -1, msg+1
+        stack:
+        ";
+        assert_eq!(
+            Program::assemble(source).unwrap().code,
+            [109, 15, 203, 1, 204, -1, 1205, 1, 2, 99, 97, 44, 59, -1, 11]
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for code in [
+            "109,100,21101,0,9,0,1106,0,11,99,0,2106,0,0",
+            "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99",
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,\
+             20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+        ] {
+            let program = Program::parse(code).unwrap();
+            let listing = program.disassemble().to_string();
+            assert_eq!(Program::assemble(&listing).unwrap(), program, "{}", listing);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let error = |source| match Program::assemble(source) {
+            Err(AocError::Parse {
+                line,
+                column,
+                message,
+                ..
+            }) => (line, column, message),
+            res => panic!("unexpected {:?}", res),
+        };
+        assert_eq!(
+            error("hlt\n  jmp 3"),
+            (2, 3, "unknown instruction".to_owned())
+        );
+        assert_eq!(error("add 1, 2"), (1, 1, "expected 3 operands".to_owned()));
+        assert_eq!(
+            error("in 3"),
+            (1, 4, "cannot write to an immediate".to_owned())
+        );
+        assert_eq!(error("out [nowhere]"), (1, 6, "unknown label".to_owned()));
+        assert_eq!(
+            error("a: hlt\na: hlt"),
+            (2, 1, "label defined twice".to_owned())
+        );
+    }
+}
@@ -0,0 +1,604 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
+
+use itertools::Itertools;
+
+use super::disasm::Line;
+use super::{asm, Fault, Opcode, Program, State};
+use crate::solutions::AocError;
+
+// Steps kept to rewind.
+const HISTORY: usize = 100_000;
+// Lines shown before and after `pc`.
+const BEFORE: usize = 4;
+const AFTER: usize = 8;
+// Cells shown around the relative base.
+const STACK: i64 = 4;
+// Steps a `continue` takes at most, for programs that never stop.
+const LIMIT: u64 = 10_000_000;
+
+// What a step changed, enough to undo it.
+struct Undo {
+    pc: usize,
+    relative_base: i64,
+    state: State,
+    len: usize,
+    // Cell written and its previous value
+    write: Option<(usize, i64)>,
+    input: Option<i64>,
+    output: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    Step,
+    Breakpoint(usize),
+    Opcode(Opcode),
+    Watch { address: usize, old: i64, new: i64 },
+    Input,
+    Finished,
+    Limit(u64),
+}
+
+impl Display for Stop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Step => write!(f, "stepped"),
+            Stop::Breakpoint(address) => write!(f, "breakpoint at {}", address),
+            Stop::Opcode(opcode) => write!(f, "breakpoint on {}", opcode.mnemonic()),
+            Stop::Watch { address, old, new } => {
+                write!(f, "[{}] changed from {} to {}", address, old, new)
+            }
+            Stop::Input => write!(f, "waiting for input"),
+            Stop::Finished => write!(f, "halted"),
+            Stop::Limit(steps) => write!(f, "paused after {} steps", steps),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Break {
+    Address(usize),
+    Opcode(Opcode),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Break),
+    Watch(usize),
+    Input(Vec<i64>),
+    Output,
+    Memory(usize, usize),
+    Back(usize),
+    Save,
+    Load(usize),
+    Help,
+    Quit,
+}
+
+pub const HELP: &str = "\
+s, step [N]          executes N instructions, 1 by default
+c, continue          runs until a breakpoint, a watch, an input or the end
+b, break ADDR|OP     toggles a breakpoint on an address or an opcode
+w, watch ADDR        toggles a watchpoint on a memory cell
+i, input N.. | \"s\"   queues numbers, or the characters of a string
+o, output            consumes the outputs
+m, mem ADDR [N]      shows N cells from ADDR
+back [N]             rewinds N steps
+save                 takes a snapshot
+load N               goes back to snapshot N
+q, quit";
+
+fn number<T: std::str::FromStr>(arg: Option<&str>, default: Option<T>) -> Result<T, String> {
+    match arg {
+        Some(arg) => arg
+            .parse()
+            .map_err(|_| format!("expected a number, got `{}`", arg)),
+        None => default.ok_or_else(|| "expected a number".to_owned()),
+    }
+}
+
+// Characters of a string, with the escapes of the assembler.
+fn parse_text(s: &str) -> Result<Vec<i64>, String> {
+    match asm::parse_text(s, s) {
+        Ok(text) => Ok(text.chars().map(|c| c as i64).collect()),
+        Err(AocError::Parse { message, .. }) => Err(message),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Command, String> {
+        let line = line.trim();
+        let (name, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let mut args = rest.split_whitespace();
+        let command = match name {
+            "s" | "step" => Command::Step(number(args.next(), Some(1))?),
+            "c" | "continue" => Command::Continue,
+            "b" | "break" => {
+                let arg = args.next().ok_or("expected an address or an opcode")?;
+                match Opcode::from_mnemonic(arg) {
+                    Some(opcode) => Command::Break(Break::Opcode(opcode)),
+                    None => Command::Break(Break::Address(number(Some(arg), None)?)),
+                }
+            }
+            "w" | "watch" => Command::Watch(number(args.next(), None)?),
+            "i" | "input" if rest.trim_start().starts_with('"') => {
+                return parse_text(rest.trim()).map(Command::Input);
+            }
+            "i" | "input" => {
+                let values = rest
+                    .split([' ', ','])
+                    .filter(|v| !v.is_empty())
+                    .map(|v| number(Some(v), None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if values.is_empty() {
+                    return Err("expected values".to_owned());
+                }
+                return Ok(Command::Input(values));
+            }
+            "o" | "output" => Command::Output,
+            "m" | "mem" => {
+                Command::Memory(number(args.next(), None)?, number(args.next(), Some(8))?)
+            }
+            "back" => Command::Back(number(args.next(), Some(1))?),
+            "save" => Command::Save,
+            "load" => Command::Load(number(args.next(), None)?),
+            "h" | "help" => Command::Help,
+            "q" | "quit" => Command::Quit,
+            _ => return Err(format!("unknown command `{}`, `help` lists them", name)),
+        };
+        match args.next() {
+            Some(arg) => Err(format!("unexpected `{}`", arg)),
+            None => Ok(command),
+        }
+    }
+}
+
+// Runs a program step by step. Every step can be undone, and snapshots keep
+// whole states to come back to.
+pub struct Debugger {
+    pub program: Program,
+    pub steps: u64,
+    // Steps of a `resume`
+    limit: u64,
+    breakpoints: BTreeSet<usize>,
+    opcodes: Vec<Opcode>,
+    // Watched cells and their last known values
+    watches: BTreeMap<usize, i64>,
+    history: VecDeque<Undo>,
+    snapshots: Vec<(Program, u64)>,
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Self {
+        Debugger {
+            program,
+            steps: 0,
+            limit: LIMIT,
+            breakpoints: BTreeSet::new(),
+            opcodes: vec![],
+            watches: BTreeMap::new(),
+            history: VecDeque::new(),
+            snapshots: vec![],
+        }
+    }
+
+    pub fn step(&mut self) -> Result<Stop, Fault> {
+        let p = &mut self.program;
+        if p.is_complete() {
+            return Ok(Stop::Finished);
+        }
+        let ins = p.current()?;
+        let write = ins
+            .opcode
+            .writes()
+            .and_then(|i| p.address(i, ins.modes[i]).ok())
+            .map(|a| (a, p.read(a)));
+        let input = match ins.opcode {
+            Opcode::In => p.input.front().copied(),
+            _ => None,
+        };
+        let mut undo = Undo {
+            pc: p.pc,
+            relative_base: p.relative_base,
+            state: p.state,
+            len: p.code.len(),
+            write,
+            input,
+            output: false,
+        };
+        let outputs = p.output.len();
+        let state = p.step()?;
+        // Blocked on an empty input: nothing executed, so nothing to undo
+        if ins.opcode == Opcode::In && input.is_none() {
+            return Ok(Stop::Input);
+        }
+        undo.output = p.output.len() > outputs;
+        self.history.push_back(undo);
+        if self.history.len() > HISTORY {
+            self.history.pop_front();
+        }
+        self.steps += 1;
+        for (&address, value) in self.watches.iter_mut() {
+            let new = self.program.read(address);
+            if new != *value {
+                let old = std::mem::replace(value, new);
+                return Ok(Stop::Watch { address, old, new });
+            }
+        }
+        Ok(match state {
+            State::Input => Stop::Input,
+            State::Finished => Stop::Finished,
+            _ => Stop::Step,
+        })
+    }
+
+    // Steps until something worth stopping for, or the step limit. Breakpoints
+    // are checked before executing an instruction, except the first one, so
+    // that continuing leaves the breakpoint stopped at.
+    pub fn resume(&mut self) -> Result<Stop, Fault> {
+        let mut first = true;
+        for _ in 0..self.limit {
+            if !first {
+                let pc = self.program.pc;
+                if self.breakpoints.contains(&pc) {
+                    return Ok(Stop::Breakpoint(pc));
+                }
+                if let Ok(ins) = self.program.current() {
+                    if self.opcodes.contains(&ins.opcode) {
+                        return Ok(Stop::Opcode(ins.opcode));
+                    }
+                }
+            }
+            first = false;
+            match self.step()? {
+                Stop::Step => {}
+                stop => return Ok(stop),
+            }
+        }
+        Ok(Stop::Limit(self.limit))
+    }
+
+    // Undoes up to `n` steps, and returns how many were.
+    pub fn back(&mut self, n: usize) -> usize {
+        let mut undone = 0;
+        while undone < n {
+            let Some(undo) = self.history.pop_back() else {
+                break;
+            };
+            let p = &mut self.program;
+            p.pc = undo.pc;
+            p.relative_base = undo.relative_base;
+            p.state = undo.state;
+            if let Some((address, value)) = undo.write {
+                if address < undo.len {
+                    p.code[address] = value;
+                }
+            }
+            p.code.truncate(undo.len);
+            if let Some(value) = undo.input {
+                p.input.push_front(value);
+            }
+            if undo.output {
+                p.output.pop_back();
+            }
+            self.steps -= 1;
+            undone += 1;
+        }
+        self.sync_watches();
+        undone
+    }
+
+    pub fn save(&mut self) -> usize {
+        self.snapshots.push((self.program.clone(), self.steps));
+        self.snapshots.len() - 1
+    }
+
+    // The steps taken before cannot be rewound anymore.
+    pub fn load(&mut self, i: usize) -> bool {
+        let Some((program, steps)) = self.snapshots.get(i) else {
+            return false;
+        };
+        self.program = program.clone();
+        self.steps = *steps;
+        self.history.clear();
+        self.sync_watches();
+        true
+    }
+
+    fn sync_watches(&mut self) {
+        for (&address, value) in self.watches.iter_mut() {
+            *value = self.program.read(address);
+        }
+    }
+
+    // Runs a command, and tells how it went.
+    pub fn execute(&mut self, command: Command) -> String {
+        let stop = match command {
+            Command::Step(n) => (0..n.max(1))
+                .map(|_| self.step())
+                .find_or_last(|s| !matches!(s, Ok(Stop::Step)))
+                .unwrap(),
+            Command::Continue => self.resume(),
+            Command::Break(Break::Address(address)) => {
+                let on = !self.breakpoints.remove(&address);
+                if on {
+                    self.breakpoints.insert(address);
+                }
+                return format!(
+                    "breakpoint at {} {}",
+                    address,
+                    if on { "set" } else { "removed" }
+                );
+            }
+            Command::Break(Break::Opcode(opcode)) => {
+                let on = !self.opcodes.contains(&opcode);
+                if on {
+                    self.opcodes.push(opcode);
+                } else {
+                    self.opcodes.retain(|&o| o != opcode);
+                }
+                return format!(
+                    "breakpoint on {} {}",
+                    opcode.mnemonic(),
+                    if on { "set" } else { "removed" }
+                );
+            }
+            Command::Watch(address) => {
+                if self.watches.remove(&address).is_some() {
+                    return format!("[{}] unwatched", address);
+                }
+                self.watches.insert(address, self.program.read(address));
+                return format!("[{}] watched", address);
+            }
+            Command::Input(values) => {
+                let queued = values.iter().join(", ");
+                values.into_iter().for_each(|v| self.program.set_input(v));
+                return format!("queued {}", queued);
+            }
+            Command::Output => {
+                let values = self.program.output.drain(..).collect_vec();
+                return format!("outputs: {}", values.iter().join(", "));
+            }
+            Command::Memory(address, n) => {
+                return (address..address + n)
+                    .map(|a| format!("[{}]={}", a, self.program.read(a)))
+                    .join(" ");
+            }
+            Command::Back(n) => return format!("{} steps back", self.back(n)),
+            Command::Save => return format!("snapshot {} taken", self.save()),
+            Command::Load(i) if self.load(i) => return format!("snapshot {} loaded", i),
+            Command::Load(i) => return format!("no snapshot {}", i),
+            Command::Help => return HELP.to_owned(),
+            Command::Quit => return String::new(),
+        };
+        match stop {
+            Ok(stop) => stop.to_string(),
+            Err(fault) => format!("fault: {}", fault),
+        }
+    }
+
+    // State of the program: the code around `pc`, marked with `>`, and `*`
+    // on breakpoints, then the cells around the relative base and the queues.
+    pub fn view(&self) -> String {
+        let p = &self.program;
+        let listing = p.disassemble();
+        let mut lines = listing
+            .lines
+            .iter()
+            .filter(|l| l.address() + l.len() <= p.pc)
+            .rev()
+            .take(BEFORE)
+            .cloned()
+            .collect_vec();
+        lines.reverse();
+        let mut address = p.pc;
+        for _ in 0..AFTER {
+            if address >= p.code.len() {
+                break;
+            }
+            let line = Line::at(&p.code, address);
+            address += line.len();
+            lines.push(line);
+        }
+
+        let mut view = vec![format!(
+            "{:?} at {}, relative base {}, {} steps",
+            p.state, p.pc, p.relative_base, self.steps
+        )];
+        view.extend(lines.iter().map(|line| {
+            let at = line.address();
+            let mark = match (at == p.pc, self.breakpoints.contains(&at)) {
+                (true, true) => ">*",
+                (true, false) => "> ",
+                (false, true) => " *",
+                (false, false) => "  ",
+            };
+            format!("{} {}", mark, listing.row(line))
+        }));
+        let stack = (-STACK..=STACK)
+            .map(|i| p.relative_base + i)
+            .filter_map(|a| usize::try_from(a).ok())
+            .map(|a| format!("{}={}", a, p.read(a)))
+            .join(" ");
+        let ascii = |q: &VecDeque<i64>| {
+            let text: String = q
+                .iter()
+                .filter_map(|&v| u8::try_from(v).ok())
+                .filter(|&b| b == b'\n' || (b' '..=b'~').contains(&b))
+                .map(char::from)
+                .collect();
+            if !q.is_empty() && text.len() == q.len() {
+                format!("  {:?}", text)
+            } else {
+                String::new()
+            }
+        };
+        view.push(format!("stack   {}", stack));
+        view.push(format!(
+            "input   {}{}",
+            p.input.iter().join(", "),
+            ascii(&p.input)
+        ));
+        view.push(format!(
+            "output  {}{}",
+            p.output.iter().join(", "),
+            ascii(&p.output)
+        ));
+        let breaks = self
+            .breakpoints
+            .iter()
+            .map(|b| b.to_string())
+            .chain(self.opcodes.iter().map(|o| o.mnemonic().to_owned()))
+            .join(", ");
+        view.push(format!("breaks  {}", breaks));
+        view.push(format!(
+            "watch   {}",
+            self.watches
+                .iter()
+                .map(|(a, v)| format!("[{}]={}", a, v))
+                .join(" ")
+        ));
+        view.push(format!("snapshots {}", self.snapshots.len()));
+        view.iter().map(|l| l.trim_end()).join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Break, Command, Debugger, Stop};
+    use crate::solutions::y2019::intcode::{Opcode, Program, State};
+
+    // Counts down from its input, outputting every value.
+    const COUNTDOWN: &str = "
+        in [n]
+    loop:
+        out [n]
+        add [n], -1, [n]
+        jnz [n], loop
+        hlt
+    n: data 0
+    ";
+
+    #[test]
+    fn commands() {
+        assert_eq!(Command::parse("s"), Ok(Command::Step(1)));
+        assert_eq!(Command::parse("step 10"), Ok(Command::Step(10)));
+        assert_eq!(
+            Command::parse("b out"),
+            Ok(Command::Break(Break::Opcode(Opcode::Out)))
+        );
+        assert_eq!(
+            Command::parse("b 12"),
+            Ok(Command::Break(Break::Address(12)))
+        );
+        assert_eq!(
+            Command::parse("i 1, -2 3"),
+            Ok(Command::Input(vec![1, -2, 3]))
+        );
+        assert_eq!(
+            Command::parse("i \"A\\n\""),
+            Ok(Command::Input(vec![65, 10]))
+        );
+        assert_eq!(
+            Command::parse("i \"\\t\\\"\""),
+            Ok(Command::Input(vec![9, 34]))
+        );
+        assert!(Command::parse("i \"A").is_err());
+        assert_eq!(Command::parse("m 3"), Ok(Command::Memory(3, 8)));
+        assert!(Command::parse("s x").is_err());
+        assert!(Command::parse("b").is_err());
+        assert!(Command::parse("jump").is_err());
+    }
+
+    #[test]
+    fn stops() {
+        let mut d = Debugger::new(Program::assemble(COUNTDOWN).unwrap());
+        assert_eq!(d.resume(), Ok(Stop::Input));
+        d.execute(Command::Input(vec![3]));
+        d.execute(Command::Break(Break::Opcode(Opcode::Out)));
+        assert_eq!(d.resume(), Ok(Stop::Opcode(Opcode::Out)));
+        assert_eq!(d.program.pc, 2);
+        d.execute(Command::Break(Break::Opcode(Opcode::Out)));
+        d.execute(Command::Watch(12));
+        assert_eq!(
+            d.resume(),
+            Ok(Stop::Watch {
+                address: 12,
+                old: 3,
+                new: 2
+            })
+        );
+        d.execute(Command::Break(Break::Address(11)));
+        assert_eq!(d.execute(Command::Continue), "[12] changed from 2 to 1");
+        assert_eq!(d.execute(Command::Continue), "[12] changed from 1 to 0");
+        assert_eq!(d.resume(), Ok(Stop::Breakpoint(11)));
+        assert_eq!(d.resume(), Ok(Stop::Finished));
+        assert_eq!(d.execute(Command::Output), "outputs: 3, 2, 1");
+    }
+
+    #[test]
+    fn limit() {
+        let mut d = Debugger::new(Program::assemble("loop: jz 0, loop").unwrap());
+        d.limit = 10;
+        assert_eq!(d.resume(), Ok(Stop::Limit(10)));
+        assert_eq!(d.steps, 10);
+        assert_eq!(d.execute(Command::Continue), "paused after 10 steps");
+    }
+
+    #[test]
+    fn blocked_input() {
+        let mut d = Debugger::new(Program::assemble(COUNTDOWN).unwrap());
+        assert_eq!(d.step(), Ok(Stop::Input));
+        assert_eq!(d.step(), Ok(Stop::Input));
+        assert_eq!(d.steps, 0);
+        assert_eq!(d.back(1), 0);
+    }
+
+    #[test]
+    fn rewinds() {
+        let mut d = Debugger::new(Program::assemble(COUNTDOWN).unwrap());
+        d.program.set_input(2);
+        let start = d.program.clone();
+        assert_eq!(d.execute(Command::Save), "snapshot 0 taken");
+        assert_eq!(d.resume(), Ok(Stop::Finished));
+        let steps = d.steps as usize;
+        assert_eq!(d.back(steps + 5), steps);
+        assert_eq!(d.program, start);
+
+        d.execute(Command::Step(3));
+        assert_eq!(d.program.read(12), 1);
+        assert_eq!(d.execute(Command::Load(0)), "snapshot 0 loaded");
+        assert_eq!(d.program, start);
+        assert_eq!(d.steps, 0);
+        assert_eq!(d.execute(Command::Load(1)), "no snapshot 1");
+    }
+
+    #[test]
+    fn view() {
+        let mut d = Debugger::new(Program::assemble(COUNTDOWN).unwrap());
+        d.program.set_input(65);
+        d.execute(Command::Break(Break::Address(4)));
+        d.execute(Command::Step(2));
+        assert_eq!(d.program.state(), State::Output);
+        assert_eq!(
+            d.view(),
+            r#"Output at 4, relative base 0, 2 steps
+           in [12]                         ;    0  3,12
+   L2:     out [12]                        ;    2  4,12  [12]=65
+>*         add [12], -1, [12]              ;    4  1001,12,-1,12  [12]=65
+           jnz [12], L2                    ;    8  1005,12,2  [12]=65
+           hlt                             ;   11  99
+           data 65                         ;   12  "A"
+stack   0=3 1=12 2=4 3=12 4=1001
+input
+output  65  "A"
+breaks  4
+watch
+snapshots 0"#
+        );
+    }
+}
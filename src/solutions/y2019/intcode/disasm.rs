@@ -0,0 +1,315 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+
+use itertools::Itertools;
+
+use super::{Instruction, Mode, Opcode, Program};
+
+// Words per line of a data region.
+const DATA_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Code {
+        address: usize,
+        instruction: Instruction,
+        params: Vec<i64>,
+    },
+    Data {
+        address: usize,
+        words: Vec<i64>,
+    },
+}
+
+impl Line {
+    // Line decoded at any address, a single data word if no instruction fits.
+    pub fn at(code: &[i64], address: usize) -> Line {
+        match decode_at(code, address) {
+            Some(instruction) => Line::Code {
+                address,
+                instruction,
+                params: code[address + 1..address + instruction.len()].to_vec(),
+            },
+            None => Line::Data {
+                address,
+                words: vec![code.get(address).copied().unwrap_or(0)],
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Line::Code { instruction, .. } => instruction.len(),
+            Line::Data { words, .. } => words.len(),
+        }
+    }
+
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Code { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+}
+
+// Annotated listing of a program. It reads as assembly source: labels and
+// instructions on the left, addresses and raw words in the comments.
+pub struct Listing<'a> {
+    code: &'a [i64],
+    pub lines: Vec<Line>,
+    pub labels: BTreeMap<usize, String>,
+    // Return addresses, labeled wherever they are pushed
+    returns: BTreeSet<usize>,
+}
+
+fn decode_at(code: &[i64], address: usize) -> Option<Instruction> {
+    let ins = Instruction::decode(*code.get(address)?)?;
+    (address + ins.len() <= code.len()).then_some(ins)
+}
+
+// Jumps taken whatever the state, which execution never falls through.
+fn always_jumps(ins: &Instruction, params: &[i64]) -> bool {
+    match ins.opcode {
+        Opcode::JumpTrue | Opcode::JumpFalse => {
+            ins.modes[0] == Mode::Immediate && (params[0] != 0) == (ins.opcode == Opcode::JumpTrue)
+        }
+        Opcode::Halt => true,
+        _ => false,
+    }
+}
+
+// Addresses of the instructions reachable from 0, and the jump targets and
+// return addresses met along the way. Indirect jumps cannot be followed, so
+// the address after an unconditional jump counts as a return address when
+// the program uses it as an immediate, as calls push it.
+fn explore(code: &[i64]) -> (BTreeSet<usize>, BTreeSet<usize>, BTreeSet<usize>) {
+    let mut starts = BTreeSet::new();
+    let mut targets = BTreeSet::new();
+    let mut returns = BTreeSet::new();
+    let mut after_jumps = BTreeSet::new();
+    let mut immediates = BTreeSet::new();
+    let mut seen = BTreeSet::from([0]);
+    let mut todo = vec![0];
+    loop {
+        while let Some(address) = todo.pop() {
+            let Some(ins) = decode_at(code, address) else {
+                continue;
+            };
+            starts.insert(address);
+            let params = &code[address + 1..address + ins.len()];
+            for (mode, &p) in ins.modes.iter().zip(params) {
+                if *mode == Mode::Immediate {
+                    immediates.insert(p);
+                }
+            }
+            let mut next = vec![];
+            if matches!(ins.opcode, Opcode::JumpTrue | Opcode::JumpFalse)
+                && ins.modes[1] == Mode::Immediate
+            {
+                if let Ok(target) = usize::try_from(params[1]) {
+                    targets.insert(target);
+                    next.push(target);
+                }
+            }
+            if always_jumps(&ins, params) {
+                after_jumps.insert(address + ins.len());
+            } else {
+                next.push(address + ins.len());
+            }
+            todo.extend(next.into_iter().filter(|&a| seen.insert(a)));
+        }
+        let found = after_jumps
+            .iter()
+            .copied()
+            .filter(|&a| !seen.contains(&a) && immediates.contains(&(a as i64)))
+            .collect_vec();
+        if found.is_empty() {
+            return (starts, targets, returns);
+        }
+        for address in found {
+            seen.insert(address);
+            returns.insert(address);
+            todo.push(address);
+        }
+    }
+}
+
+impl Program {
+    // Disassembles the memory as it is now. Code is told apart from data by
+    // following the control flow from 0: whatever cannot be reached is data.
+    pub fn disassemble(&self) -> Listing<'_> {
+        let code = &self.code;
+        let (starts, targets, returns) = explore(code);
+        let mut lines = vec![];
+        let mut address = 0;
+        while address < code.len() {
+            if starts.contains(&address) {
+                let instruction = decode_at(code, address).unwrap();
+                let end = address + instruction.len();
+                lines.push(Line::Code {
+                    address,
+                    instruction,
+                    params: code[address + 1..end].to_vec(),
+                });
+                address = end;
+            } else {
+                let start = address;
+                address += 1;
+                while address < code.len()
+                    && address - start < DATA_WIDTH
+                    && !starts.contains(&address)
+                    && !targets.contains(&address)
+                {
+                    address += 1;
+                }
+                lines.push(Line::Data {
+                    address: start,
+                    words: code[start..address].to_vec(),
+                });
+            }
+        }
+        // A target in the middle of an instruction cannot be labeled.
+        let labels = lines
+            .iter()
+            .map(Line::address)
+            .filter(|a| targets.contains(a) || returns.contains(a))
+            .map(|a| (a, format!("L{}", a)))
+            .collect();
+        Listing {
+            code,
+            lines,
+            labels,
+            returns,
+        }
+    }
+}
+
+impl Listing<'_> {
+    fn operand(&self, ins: &Instruction, i: usize, value: i64) -> String {
+        match ins.modes[i] {
+            Mode::Position => format!("[{}]", value),
+            Mode::Relative if value < 0 => format!("[rb-{}]", -value),
+            Mode::Relative => format!("[rb+{}]", value),
+            Mode::Immediate => {
+                let jump = i == 1 && matches!(ins.opcode, Opcode::JumpTrue | Opcode::JumpFalse);
+                usize::try_from(value)
+                    .ok()
+                    .filter(|a| jump || self.returns.contains(a))
+                    .and_then(|a| self.labels.get(&a))
+                    .cloned()
+                    .unwrap_or_else(|| value.to_string())
+            }
+        }
+    }
+
+    // Line as printed in the listing, which it need not be part of.
+    pub fn row(&self, line: &Line) -> String {
+        let label = self
+            .labels
+            .get(&line.address())
+            .map(|l| format!("{}:", l))
+            .unwrap_or_default();
+        let (text, comment) = self.text(line);
+        let row = format!(
+            "{:<8}{:<32}; {:>4}  {}",
+            label,
+            text,
+            line.address(),
+            comment
+        );
+        row.trim_end().to_owned()
+    }
+
+    // Instruction or data, and what the comment says about it.
+    fn text(&self, line: &Line) -> (String, String) {
+        match line {
+            Line::Code {
+                address,
+                instruction,
+                params,
+            } => {
+                let operands = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &p)| self.operand(instruction, i, p))
+                    .join(", ");
+                // Values read from fixed cells, as the program starts
+                let reads = params
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| {
+                        instruction.modes[i] == Mode::Position
+                            && instruction.opcode.writes() != Some(i)
+                    })
+                    .filter_map(|(_, &p)| usize::try_from(p).ok())
+                    .map(|a| format!("[{}]={}", a, self.code.get(a).copied().unwrap_or(0)))
+                    .join(" ");
+                let text = format!("{} {}", instruction.opcode.mnemonic(), operands);
+                let raw = self.code[*address..address + instruction.len()]
+                    .iter()
+                    .join(",");
+                (text.trim_end().to_owned(), format!("{}  {}", raw, reads))
+            }
+            Line::Data { words, .. } => {
+                let text = format!("data {}", words.iter().join(", "));
+                let ascii = words
+                    .iter()
+                    .all(|&w| w == 10 || (32..127).contains(&w))
+                    .then(|| words.iter().map(|&w| w as u8 as char).collect::<String>());
+                (text, ascii.map(|s| format!("{:?}", s)).unwrap_or_default())
+            }
+        }
+    }
+}
+
+impl Display for Listing<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{}", self.row(line))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Line;
+    use crate::solutions::y2019::intcode::Program;
+
+    #[test]
+    fn listing() {
+        let program = Program::parse("109,100,21101,0,9,0,1106,0,11,99,0,2106,0,0").unwrap();
+        let listing = program.disassemble();
+        assert_eq!(
+            listing.to_string(),
+            "        arb 100                         ;    0  109,100
+        add 0, L9, [rb+0]               ;    2  21101,0,9,0
+        jz 0, L11                       ;    6  1106,0,11
+L9:     hlt                             ;    9  99
+        data 0                          ;   10
+L11:    jz 0, [rb+0]                    ;   11  2106,0,0
+"
+        );
+    }
+
+    #[test]
+    fn data() {
+        let program = Program::parse("1001,9,-1,9,4,10,99,104,105,10").unwrap();
+        let listing = program.disassemble();
+        assert_eq!(
+            listing.lines[3],
+            Line::Data {
+                address: 7,
+                words: vec![104, 105, 10]
+            }
+        );
+        assert_eq!(
+            listing.to_string().lines().take(4).collect::<Vec<_>>(),
+            [
+                "        add [9], -1, [9]                ;    0  1001,9,-1,9  [9]=10",
+                "        out [10]                        ;    4  4,10  [10]=0",
+                "        hlt                             ;    6  99",
+                "        data 104, 105, 10               ;    7  \"hi\\n\"",
+            ]
+        );
+    }
+}
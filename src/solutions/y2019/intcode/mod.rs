@@ -0,0 +1,452 @@
+use std::collections::VecDeque;
+
+use thiserror::Error;
+
+use crate::solutions::AocError;
+
+pub mod asm;
+pub mod debug;
+pub mod disasm;
+pub mod trace;
+
+// Where a program stands between two calls to `run`: `Input` waits for a
+// value, `Output` holds values to consume.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum State {
+    Start,
+    Input,
+    Output,
+    Running,
+    Finished,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Mode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    fn from_digit(digit: i64) -> Option<Mode> {
+        match digit {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+
+    fn digit(self) -> i64 {
+        match self {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Opcode {
+    Add,
+    Mul,
+    In,
+    Out,
+    JumpTrue,
+    JumpFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Mul,
+        Opcode::In,
+        Opcode::Out,
+        Opcode::JumpTrue,
+        Opcode::JumpFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustBase,
+        Opcode::Halt,
+    ];
+
+    pub fn code(self) -> i64 {
+        match self {
+            Opcode::Add => 1,
+            Opcode::Mul => 2,
+            Opcode::In => 3,
+            Opcode::Out => 4,
+            Opcode::JumpTrue => 5,
+            Opcode::JumpFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustBase => 9,
+            Opcode::Halt => 99,
+        }
+    }
+
+    pub fn from_code(code: i64) -> Option<Opcode> {
+        Opcode::ALL.into_iter().find(|o| o.code() == code)
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Mul => "mul",
+            Opcode::In => "in",
+            Opcode::Out => "out",
+            Opcode::JumpTrue => "jnz",
+            Opcode::JumpFalse => "jz",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        Opcode::ALL.into_iter().find(|o| o.mnemonic() == mnemonic)
+    }
+
+    pub fn arity(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpTrue | Opcode::JumpFalse => 2,
+            Opcode::In | Opcode::Out | Opcode::AdjustBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    // Index of the parameter written to.
+    pub fn writes(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::In => Some(0),
+            _ => None,
+        }
+    }
+}
+
+// Opcode and parameter modes, as encoded in the first word of an instruction.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    // Only accepts what a valid program may execute: no mode digit past the
+    // parameters, and no immediate mode for the parameter written to.
+    pub fn decode(word: i64) -> Option<Instruction> {
+        if word < 0 {
+            return None;
+        }
+        let opcode = Opcode::from_code(word % 100)?;
+        if word >= 10i64.pow(2 + opcode.arity() as u32) {
+            return None;
+        }
+        let mut modes = [Mode::Position; 3];
+        let mut digits = word / 100;
+        for mode in modes.iter_mut() {
+            *mode = Mode::from_digit(digits % 10)?;
+            digits /= 10;
+        }
+        if opcode
+            .writes()
+            .is_some_and(|i| modes[i] == Mode::Immediate)
+        {
+            return None;
+        }
+        Some(Instruction { opcode, modes })
+    }
+
+    // Modes past the parameters are left out, as `decode` expects.
+    pub fn encode(&self) -> i64 {
+        self.modes[..self.opcode.arity()]
+            .iter()
+            .rev()
+            .fold(0, |acc, m| acc * 10 + m.digit())
+            * 100
+            + self.opcode.code()
+    }
+
+    pub fn len(&self) -> usize {
+        1 + self.opcode.arity()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Fault {
+    #[error("invalid instruction {word} at {address}")]
+    InvalidInstruction { address: usize, word: i64 },
+    #[error("negative address {target} used at {address}")]
+    NegativeAddress { address: usize, target: i64 },
+}
+
+// Intcode machine. Memory grows on writes and reads as 0 past its end.
+// Inputs are queued until read, outputs until consumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub code: Vec<i64>,
+    pub pc: usize,
+    pub relative_base: i64,
+    state: State,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+}
+
+impl Program {
+    pub fn new(code: Vec<i64>) -> Self {
+        Program {
+            code,
+            pc: 0,
+            relative_base: 0,
+            state: State::Start,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        input
+            .trim_end()
+            .split(',')
+            .map(|w| {
+                w.trim()
+                    .parse()
+                    .map_err(|_| AocError::parse(input, w, "expected an integer"))
+            })
+            .collect::<Result<_, _>>()
+            .map(Program::new)
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.state == State::Finished
+    }
+
+    pub fn set_input(&mut self, input: i64) {
+        self.input.push_back(input);
+    }
+
+    pub fn consume_output(&mut self) -> i64 {
+        self.output
+            .pop_front()
+            .expect("no output to consume")
+    }
+
+    pub fn read(&self, address: usize) -> i64 {
+        self.code.get(address).copied().unwrap_or(0)
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.code.len() {
+            self.code.resize(address + 1, 0);
+        }
+        self.code[address] = value;
+    }
+
+    // Instruction at `pc`.
+    pub fn current(&self) -> Result<Instruction, Fault> {
+        let word = self.read(self.pc);
+        Instruction::decode(word).ok_or(Fault::InvalidInstruction {
+            address: self.pc,
+            word,
+        })
+    }
+
+    // Address designated by the parameter `i` of the current instruction.
+    fn address(&self, i: usize, mode: Mode) -> Result<usize, Fault> {
+        let raw = self.read(self.pc + 1 + i);
+        let target = match mode {
+            Mode::Position => raw,
+            Mode::Relative => self.relative_base + raw,
+            Mode::Immediate => return Ok(self.pc + 1 + i),
+        };
+        usize::try_from(target).map_err(|_| Fault::NegativeAddress {
+            address: self.pc,
+            target,
+        })
+    }
+
+    fn param(&self, ins: &Instruction, i: usize) -> Result<i64, Fault> {
+        Ok(self.read(self.address(i, ins.modes[i])?))
+    }
+
+    // Executes the instruction at `pc`. Reading an empty input queue leaves
+    // `pc` there, in the `Input` state.
+    pub fn step(&mut self) -> Result<State, Fault> {
+        let ins = self.current()?;
+        let (start, next) = (self.pc, self.pc + ins.len());
+        self.state = State::Running;
+        let mut io = None;
+        match ins.opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => {
+                let (a, b) = (self.param(&ins, 0)?, self.param(&ins, 1)?);
+                let value = match ins.opcode {
+                    Opcode::Add => a + b,
+                    Opcode::Mul => a * b,
+                    Opcode::LessThan => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                let address = self.address(2, ins.modes[2])?;
+                self.write(address, value);
+                self.pc = next;
+            }
+            Opcode::In => match self.input.pop_front() {
+                Some(value) => {
+                    let address = self.address(0, ins.modes[0])?;
+                    self.write(address, value);
+                    self.pc = next;
+                    io = Some(trace::Io::Input(value));
+                }
+                None => self.state = State::Input,
+            },
+            Opcode::Out => {
+                let value = self.param(&ins, 0)?;
+                self.output.push_back(value);
+                self.pc = next;
+                io = Some(trace::Io::Output(value));
+                self.state = State::Output;
+            }
+            Opcode::JumpTrue | Opcode::JumpFalse => {
+                let cond = self.param(&ins, 0)?;
+                if (cond != 0) == (ins.opcode == Opcode::JumpTrue) {
+                    let target = self.param(&ins, 1)?;
+                    self.pc = usize::try_from(target).map_err(|_| Fault::NegativeAddress {
+                        address: self.pc,
+                        target,
+                    })?;
+                } else {
+                    self.pc = next;
+                }
+            }
+            Opcode::AdjustBase => {
+                self.relative_base += self.param(&ins, 0)?;
+                self.pc = next;
+            }
+            Opcode::Halt => self.state = State::Finished,
+        }
+        if self.state != State::Input {
+            trace::executed(start, ins.opcode, io);
+        }
+        Ok(self.state)
+    }
+
+    // Runs until the program waits for an input, outputs a value or halts.
+    // Returns `Output` right away while outputs are left to consume.
+    pub fn try_run(&mut self) -> Result<State, Fault> {
+        if self.is_complete() {
+            return Ok(State::Finished);
+        }
+        if !self.output.is_empty() {
+            return Ok(State::Output);
+        }
+        loop {
+            match self.step()? {
+                State::Running => {}
+                state => return Ok(state),
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> State {
+        self.try_run().unwrap_or_else(|f| panic!("intcode fault: {}", f))
+    }
+
+    // Runs to completion, or until the inputs run out, and returns all the
+    // outputs. Inputs are read in order, after any already queued.
+    pub fn run_with_input(&mut self, input: Vec<i64>) -> Vec<i64> {
+        self.input.extend(input);
+        let mut output = vec![];
+        while self.run() == State::Output {
+            output.push(self.consume_output());
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fault, Instruction, Mode, Opcode, Program, State};
+
+    fn run(code: &str, input: Vec<i64>) -> Vec<i64> {
+        Program::parse(code).unwrap().run_with_input(input)
+    }
+
+    #[test]
+    fn day2() {
+        let mut p = Program::parse("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+        assert_eq!(p.run(), State::Finished);
+        assert_eq!(p.code[0], 3500);
+    }
+
+    #[test]
+    fn day5() {
+        let code = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,\
+                    1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,\
+                    1105,1,46,98,99";
+        assert_eq!(run(code, vec![7]), [999]);
+        assert_eq!(run(code, vec![8]), [1000]);
+        assert_eq!(run(code, vec![9]), [1001]);
+        assert!(run("1002,4,3,4,33", vec![]).is_empty());
+    }
+
+    #[test]
+    fn day9() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected: Vec<i64> = quine.split(',').map(|w| w.parse().unwrap()).collect();
+        assert_eq!(run(quine, vec![]), expected);
+        assert_eq!(
+            run("1102,34915192,34915192,7,4,7,99,0", vec![]),
+            [1219070632396864]
+        );
+        assert_eq!(run("104,1125899906842624,99", vec![]), [1125899906842624]);
+    }
+
+    #[test]
+    fn io() {
+        let mut p = Program::parse("3,0,4,0,3,0,4,0,99").unwrap();
+        assert_eq!(p.run(), State::Input);
+        assert_eq!(p.run(), State::Input);
+        p.set_input(5);
+        p.set_input(6);
+        assert_eq!(p.run(), State::Output);
+        assert_eq!(p.run(), State::Output);
+        assert_eq!(p.consume_output(), 5);
+        assert_eq!(p.run(), State::Output);
+        assert_eq!(p.consume_output(), 6);
+        assert_eq!(p.run(), State::Finished);
+        assert!(p.is_complete());
+        assert_eq!(run("3,0,4,0,3,0,4,0,99", vec![5, 6]), [5, 6]);
+    }
+
+    #[test]
+    fn instructions() {
+        let ins = Instruction::decode(21101).unwrap();
+        assert_eq!(ins.opcode, Opcode::Add);
+        assert_eq!(ins.modes, [Mode::Immediate, Mode::Immediate, Mode::Relative]);
+        assert_eq!(ins.encode(), 21101);
+        assert_eq!(Instruction::decode(11101), None);
+        assert_eq!(Instruction::decode(1104), None);
+        assert_eq!(Instruction::decode(-1), None);
+        assert_eq!(Instruction::decode(42), None);
+
+        let mut p = Program::parse("1,-1,0,0").unwrap();
+        assert_eq!(
+            p.try_run(),
+            Err(Fault::NegativeAddress {
+                address: 0,
+                target: -1
+            })
+        );
+        assert!(Program::parse("1,x,3").is_err());
+    }
+}
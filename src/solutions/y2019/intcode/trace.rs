@@ -0,0 +1,384 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, ErrorKind, Write};
+
+use itertools::Itertools;
+
+use super::Opcode;
+
+// Trace files start with the magic and the sampling rate. Then each record
+// is a tag byte: the opcode of an executed instruction followed by its
+// address, or an input or output followed by its value. Numbers are LEB128
+// varints, zigzag encoded for values.
+const MAGIC: &[u8; 4] = b"ICT1";
+const INPUT: u8 = 0xfe;
+const OUTPUT: u8 = 0xff;
+
+// Lines of each part of the report.
+const TOP: usize = 10;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Io {
+    Input(i64),
+    Output(i64),
+}
+
+fn write_varint(out: &mut impl Write, mut n: u64) -> io::Result<()> {
+    let mut buf = [0; 10];
+    let mut len = 0;
+    loop {
+        buf[len] = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            len += 1;
+            break;
+        }
+        buf[len] |= 0x80;
+        len += 1;
+    }
+    out.write_all(&buf[..len])
+}
+
+fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+fn unzigzag(n: u64) -> i64 {
+    (n >> 1) as i64 ^ -((n & 1) as i64)
+}
+
+// Records the instructions executed by the programs run within `run`, all of
+// them or one in `sample`. Inputs and outputs are always recorded.
+pub struct Tracer {
+    out: Box<dyn Write>,
+    sample: u64,
+    executed: u64,
+    // First write error, reported by `finish`
+    error: Option<io::Error>,
+}
+
+impl Tracer {
+    pub fn new(mut out: Box<dyn Write>, sample: u64) -> io::Result<Self> {
+        let sample = sample.max(1);
+        out.write_all(MAGIC)?;
+        write_varint(&mut out, sample)?;
+        Ok(Tracer {
+            out,
+            sample,
+            executed: 0,
+            error: None,
+        })
+    }
+
+    fn record(&mut self, address: usize, opcode: Opcode, io: Option<Io>) -> io::Result<()> {
+        self.executed += 1;
+        if self.executed.is_multiple_of(self.sample) {
+            self.out.write_all(&[opcode.code() as u8])?;
+            write_varint(&mut self.out, address as u64)?;
+        }
+        match io {
+            Some(Io::Input(v)) => {
+                self.out.write_all(&[INPUT])?;
+                write_varint(&mut self.out, zigzag(v))
+            }
+            Some(Io::Output(v)) => {
+                self.out.write_all(&[OUTPUT])?;
+                write_varint(&mut self.out, zigzag(v))
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => self.out.flush(),
+        }
+    }
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+    // Whether `ACTIVE` holds a tracer, checked first as it is cheaper to read
+    static TRACING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Puts back the tracer active before `run`, even when `f` panics.
+struct Restore(Option<Tracer>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        TRACING.with(|t| t.set(prev.is_some()));
+        ACTIVE.with(|a| a.replace(prev));
+    }
+}
+
+// Runs `f` with `tracer` recording the programs it runs, and gives it back.
+pub fn run<T>(tracer: Tracer, f: impl FnOnce() -> T) -> (T, Tracer) {
+    let restore = Restore(ACTIVE.with(|a| a.replace(Some(tracer))));
+    TRACING.with(|t| t.set(true));
+    let res = f();
+    let tracer = ACTIVE.with(|a| a.take()).unwrap();
+    drop(restore);
+    (res, tracer)
+}
+
+// Called by `Program::step` for every instruction executed.
+pub(super) fn executed(address: usize, opcode: Opcode, io: Option<Io>) {
+    if !TRACING.with(Cell::get) {
+        return;
+    }
+    ACTIVE.with(|a| {
+        if let Some(tracer) = a.borrow_mut().as_mut() {
+            if tracer.error.is_none() {
+                if let Err(e) = tracer.record(address, opcode, io) {
+                    tracer.error = Some(e);
+                }
+            }
+        }
+    });
+}
+
+// Instructions executed since the previous input or output, this one
+// included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exchange {
+    pub io: Io,
+    pub instructions: u64,
+}
+
+// Where a trace spent its instructions. Sampled counts are scaled up by the
+// sampling rate, so they are estimates.
+#[derive(Debug, Default)]
+pub struct Profile {
+    pub sample: u64,
+    pub instructions: u64,
+    pub addresses: HashMap<usize, u64>,
+    pub opcodes: BTreeMap<&'static str, u64>,
+    pub exchanges: Vec<Exchange>,
+    // Instructions after the last exchange
+    pub tail: u64,
+}
+
+fn read_byte(input: &mut impl BufRead) -> io::Result<Option<u8>> {
+    let byte = input.fill_buf()?.first().copied();
+    if byte.is_some() {
+        input.consume(1);
+    }
+    Ok(byte)
+}
+
+fn read_varint(input: &mut impl BufRead) -> io::Result<u64> {
+    let mut n = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = read_byte(input)?.ok_or(ErrorKind::UnexpectedEof)?;
+        n |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+    }
+    Err(io::Error::new(ErrorKind::InvalidData, "varint too long"))
+}
+
+impl Profile {
+    pub fn read(mut input: impl BufRead) -> io::Result<Profile> {
+        let mut magic = [0; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "not an Intcode trace",
+            ));
+        }
+        let mut profile = Profile {
+            sample: read_varint(&mut input)?,
+            ..Profile::default()
+        };
+        while let Some(tag) = read_byte(&mut input)? {
+            let io = match tag {
+                INPUT => Io::Input(unzigzag(read_varint(&mut input)?)),
+                OUTPUT => Io::Output(unzigzag(read_varint(&mut input)?)),
+                code => {
+                    let opcode = Opcode::from_code(code as i64).ok_or_else(|| {
+                        io::Error::new(ErrorKind::InvalidData, format!("invalid record {}", code))
+                    })?;
+                    let address = read_varint(&mut input)? as usize;
+                    profile.instructions += profile.sample;
+                    profile.tail += profile.sample;
+                    *profile.addresses.entry(address).or_default() += profile.sample;
+                    *profile.opcodes.entry(opcode.mnemonic()).or_default() += profile.sample;
+                    continue;
+                }
+            };
+            profile.exchanges.push(Exchange {
+                io,
+                instructions: std::mem::take(&mut profile.tail),
+            });
+        }
+        Ok(profile)
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let percent = |n: u64| 100.0 * n as f64 / self.instructions.max(1) as f64;
+        write!(
+            f,
+            "{} instructions, {} inputs and outputs",
+            self.instructions,
+            self.exchanges.len()
+        )?;
+        if self.sample > 1 {
+            write!(f, " (1 instruction in {} sampled)", self.sample)?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "hot addresses")?;
+        for (address, n) in self
+            .addresses
+            .iter()
+            .sorted_by_key(|&(a, n)| (std::cmp::Reverse(*n), *a))
+            .take(TOP)
+        {
+            writeln!(f, "  {:>6}  {:>12}  {:5.1}%", address, n, percent(*n))?;
+        }
+
+        writeln!(f, "opcodes")?;
+        for (mnemonic, n) in self
+            .opcodes
+            .iter()
+            .sorted_by_key(|&(m, n)| (std::cmp::Reverse(*n), *m))
+        {
+            writeln!(f, "  {:<6}  {:>12}  {:5.1}%", mnemonic, n, percent(*n))?;
+        }
+
+        if let Some((min, max)) = self
+            .exchanges
+            .iter()
+            .map(|e| e.instructions)
+            .minmax()
+            .into_option()
+        {
+            let total: u64 = self.exchanges.iter().map(|e| e.instructions).sum();
+            writeln!(
+                f,
+                "instructions per input or output: min {}, mean {}, max {}",
+                min,
+                total / self.exchanges.len() as u64,
+                max
+            )?;
+            for (i, e) in self
+                .exchanges
+                .iter()
+                .enumerate()
+                .sorted_by_key(|(i, e)| (std::cmp::Reverse(e.instructions), *i))
+                .take(TOP)
+            {
+                let (kind, value) = match e.io {
+                    Io::Input(v) => ("in", v),
+                    Io::Output(v) => ("out", v),
+                };
+                writeln!(
+                    f,
+                    "  #{:<6} {:<3} {:>16}  after {}",
+                    i, kind, value, e.instructions
+                )?;
+            }
+        }
+        if self.tail > 0 {
+            writeln!(
+                f,
+                "{} instructions after the last input or output",
+                self.tail
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::BufReader;
+
+    use super::{run, Exchange, Io, Profile, Tracer};
+    use crate::solutions::y2019::intcode::Program;
+
+    fn trace(name: &str, code: &str, input: Vec<i64>, sample: u64) -> Profile {
+        let path =
+            std::env::temp_dir().join(format!("advent-trace-{}-{}", std::process::id(), name));
+        let tracer = Tracer::new(Box::new(File::create(&path).unwrap()), sample).unwrap();
+        let mut program = Program::parse(code).unwrap();
+        let (_, tracer) = run(tracer, || program.run_with_input(input));
+        tracer.finish().unwrap();
+        let profile = Profile::read(BufReader::new(File::open(&path).unwrap())).unwrap();
+        std::fs::remove_file(path).unwrap();
+        profile
+    }
+
+    // Outputs its input, counting down to 1.
+    const COUNTDOWN: &str = "3,12,4,12,1001,12,-1,12,1005,12,2,99,0";
+
+    #[test]
+    fn profiles() {
+        let profile = trace("full", COUNTDOWN, vec![3], 1);
+        assert_eq!(profile.instructions, 11);
+        assert_eq!(profile.addresses[&2], 3);
+        assert_eq!(profile.addresses[&11], 1);
+        assert_eq!(profile.opcodes["jnz"], 3);
+        assert_eq!(
+            profile.exchanges,
+            [
+                Exchange {
+                    io: Io::Input(3),
+                    instructions: 1
+                },
+                Exchange {
+                    io: Io::Output(3),
+                    instructions: 1
+                },
+                Exchange {
+                    io: Io::Output(2),
+                    instructions: 3
+                },
+                Exchange {
+                    io: Io::Output(1),
+                    instructions: 3
+                },
+            ]
+        );
+        assert_eq!(profile.tail, 3);
+        let report = profile.to_string();
+        assert!(report.starts_with("11 instructions, 4 inputs and outputs\nhot addresses\n"));
+        assert!(report.contains("\n  jnz                3   27.3%\n"));
+    }
+
+    #[test]
+    fn samples() {
+        let profile = trace("sampled", COUNTDOWN, vec![300], 4);
+        let full = trace("unsampled", COUNTDOWN, vec![300], 1);
+        assert_eq!(profile.sample, 4);
+        assert_eq!(profile.exchanges.len(), full.exchanges.len());
+        assert_eq!(profile.instructions, full.instructions / 4 * 4);
+        assert!(profile.to_string().contains("(1 instruction in 4 sampled)"));
+        assert!(Profile::read(&b"ICT2"[..]).is_err());
+    }
+
+    #[test]
+    fn stops_tracing() {
+        let (_, tracer) = run(Tracer::new(Box::new(Vec::new()), 1).unwrap(), || {});
+        assert!(!super::TRACING.with(std::cell::Cell::get));
+        tracer.finish().unwrap();
+
+        let panicked = std::panic::catch_unwind(|| {
+            run(Tracer::new(Box::new(Vec::new()), 1).unwrap(), || {
+                panic!("solver")
+            })
+        });
+        assert!(panicked.is_err());
+        assert!(!super::TRACING.with(std::cell::Cell::get));
+        assert!(super::ACTIVE.with(|a| a.borrow().is_none()));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::solutions::registry::days;
+
+pub mod intcode;
+
+days! {
+    2019;
+    1 => r2019_1, "The Tyranny of the Rocket Equation", [1, 2];
+    2 => r2019_2, "1202 Program Alarm", [1, 2];
+    3 => r2019_3, "Crossed Wires", [1, 2];
+    4 => r2019_4, "Secure Container", [1, 2];
+    5 => r2019_5, "Sunny with a Chance of Asteroids", [1, 2];
+    6 => r2019_6, "Universal Orbit Map", [1, 2];
+    7 => r2019_7, "Amplification Circuit", [1, 2];
+    8 => r2019_8, "Space Image Format", [1, 2];
+    9 => r2019_9, "Sensor Boost", [1, 2];
+    10 => r2019_10, "Monitoring Station", [1, 2];
+    11 => r2019_11, "Space Police", [1, 2];
+    12 => r2019_12, "The N-Body Problem", [1, 2];
+    13 => r2019_13, "Care Package", [1, 2];
+    14 => r2019_14, "Space Stoichiometry", [1, 2];
+    15 => r2019_15, "Oxygen System", [1, 2];
+    16 => r2019_16, "Flawed Frequency Transmission", [1, 2];
+    17 => r2019_17, "Set and Forget", [1];
+    18 => r2019_18, "Many-Worlds Interpretation", [1, 2];
+    19 => r2019_19, "Tractor Beam", [1, 2];
+    20 => r2019_20, "Donut Maze", [1, 2];
+    21 => r2019_21, "Springdroid Adventure", [];
+    22 => r2019_22, "Slam Shuffle", [];
+    23 => r2019_23, "Category Six", [];
+    24 => r2019_24, "Planet of Discord", [];
+    25 => r2019_25, "Cryostasis", [];
+}
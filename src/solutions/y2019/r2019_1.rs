@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 
 pub enum Solution {}
@@ -15,21 +15,22 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<i64>;
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines()
             .map(|l| l.parse().map_err(From::from))
             .collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         Ok(input.iter()
             .map(|a| a.div_euclid(3) - 2)
             .sum())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         Ok(input.iter()
                .map(|&a|Solution::compute_fuel(a))
                .sum())
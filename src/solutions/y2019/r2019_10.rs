@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use itertools::Itertools;
 use std::collections::HashSet;
@@ -40,23 +40,23 @@ impl Map {
     }
 
     fn print(&self, point: Option<Vector2>) {
-        println!("------------");
+        let mut out = String::new();
         for i in 0..self.height() {
             for j in 0..self.width() {
                 let current = Vector2::new(j, i);
                 if point.is_some() && current == point.unwrap(){
-                    print!(" X ")
+                    out.push_str(" X ")
                 } else {
                     match self.get(current) {
-                        Position::Empty => print!(" . "),
-                        Position::Asteroid => print!(" # "),
-                        Position::Deleted(v) => print!("{:2} ",v)
+                        Position::Empty => out.push_str(" . "),
+                        Position::Asteroid => out.push_str(" # "),
+                        Position::Deleted(v) => out.push_str(&format!("{:2} ",v))
                     }
                 }
             }
-            println!()
+            out.push('\n')
         }
-        println!("------------");
+        log::trace!("\n{}", out);
     }
 
     fn ast_count(&self) -> usize {
@@ -168,9 +168,10 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Map;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let data = input
             .lines()
             .map(|l| {
@@ -187,7 +188,7 @@ impl Solver for Solution {
         })
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut m = 0;
         for i in 0..input.height() {
             for j in 0..input.width() {
@@ -200,7 +201,7 @@ impl Solver for Solution {
         Ok(m as i32)
     }
 
-    fn solve_part2(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(mut input: Self::Input) -> Result<Self::Output2, AocError> {
        let mut center = Vector2::new(0,0);
         let mut m = 0;
         for i in 0..input.height() {
@@ -223,7 +224,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_10::{Solution, Vector2};
+    use super::{Solution, Vector2};
+    use crate::solutions::test_support::example_test;
     use crate::solutions::Solver;
 
     #[test]
@@ -238,8 +240,7 @@ mod tests {
 .##.#..###
 ##...#..#.
 .#....####";
-        let res = Solution::solve(input, true).unwrap();
-        dbg!(res);
+        example_test!(Solution, input, part1 = 33);
     }
 
     #[test]
@@ -252,7 +253,7 @@ mod tests {
         let mut parsed = Solution::parse_input(input).unwrap();
         let c = Vector2::new(8, 3);
         let sorted = Solution::sort_asteroid(&mut parsed, c);
-        parsed.print(Some(c));
-        dbg!(sorted);
+        let first = [(8, 1), (9, 0), (9, 1), (10, 0), (9, 2), (11, 1), (12, 1), (11, 2), (15, 1)];
+        assert_eq!(sorted[..9], first.map(|(x, y)| Vector2::new(x, y)));
     }
 }
\ No newline at end of file
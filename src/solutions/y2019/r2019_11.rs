@@ -1,7 +1,8 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::{Program, State};
+use crate::solutions::y2019::intcode::{Program, State};
 use std::collections::HashSet;
+use itertools::Itertools;
 use crate::solutions::common::Vector2;
 
 pub enum Solution {}
@@ -125,74 +126,94 @@ impl Robot {
         self.black_panel.union(&self.white_panel).count() as i32
     }
 
-    fn print(&self) {
+    // White panels as `#`, as seen from above.
+    fn render(&self) -> String {
         let min_width = self.white_panel.iter().map(|p|p.x).min().unwrap();
         let max_width = self.white_panel.iter().map(|p|p.x).max().unwrap();
         let min_height = self.white_panel.iter().map(|p|p.y).min().unwrap();
         let max_height = self.white_panel.iter().map(|p|p.y).max().unwrap();
-        dbg!(min_width, max_width);
-        dbg!(min_height, max_height);
-
-        for y in (min_height..=max_height).rev() {
-            for x in (min_width..=max_width).rev() {
-                let p = Vector2::new(x,y);
-                if self.white_panel.contains(&p) {
-                    print!("#")
-                } else {
-                    print!(" ")
-                }
-            }
-            println!()
-        }
+
+        (min_height..=max_height).rev().map(|y| {
+            (min_width..=max_width).rev().map(|x| {
+                if self.white_panel.contains(&Vector2::new(x,y)) { '#' } else { '.' }
+            }).collect::<String>()
+        }).join("\n")
     }
 }
 
 impl Solver for Solution {
     type Input = Program;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = String;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Program::parse(input)
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(mut input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut res = Robot::new(input, false);
         res.run();
         Ok(res.paint_count())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut res = Robot::new(input, true);
         res.run();
-        res.print();
-        Ok(0)
+        Ok(res.render())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_11::Solution;
+    use super::{Robot, Solution};
     use crate::solutions::Solver;
-    use crate::solutions::intcode::Program;
+    use crate::solutions::y2019::intcode::Program;
+
+    // Paints and turns as in the example of the puzzle, whatever it reads.
+    const FAKE_ROBOT: &str = "
+        .base moves
+    loop:
+        in [color]
+        eq [rb+0], -1, [end]
+        jnz [end], done
+        out [rb+0]
+        out [rb+1]
+        arb 2
+        jz 0, loop
+    done:
+        hlt
+    color: data 0
+    end: data 0
+    moves: data 1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0, -1
+    ";
+
+    #[test]
+    fn fake_robot() {
+        let mut robot = Robot::new(Program::assemble(FAKE_ROBOT).unwrap(), false);
+        robot.run();
+        assert_eq!(robot.paint_count(), 6);
+        assert_eq!(robot.white_panel.len(), 4);
+        assert_eq!(robot.render(), "..#\n..#\n##.");
+    }
 
     #[test]
     fn e1() {
         let input = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
         let res = Program::parse(input).unwrap().run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99]);
     }
 
     #[test]
     fn e2() {
         let input = "104,1125899906842624,99";
         let res = Program::parse(input).unwrap().run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [1125899906842624]);
     }
 
     #[test]
     fn e3() {
         let input = "1102,34915192,34915192,7,4,7,99,0";
         let res = Program::parse(input).unwrap().run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [1219070632396864]);
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use nom::IResult;
 use nom::bytes::complete::tag;
@@ -43,7 +43,7 @@ impl Moon {
     }
 
     fn print(&self) {
-        println!("x: {}, y: {}, z:{} | vx: {}, vy: {}, vz: {} | energy: {}", self.pos.x, self.pos.y, self.pos.z, self.speed.x, self.speed.y, self.speed.z, self.total_energy())
+        log::trace!("x: {}, y: {}, z:{} | vx: {}, vy: {}, vz: {} | energy: {}", self.pos.x, self.pos.y, self.pos.z, self.speed.x, self.speed.y, self.speed.z, self.total_energy())
     }
 }
 
@@ -147,11 +147,9 @@ impl Universe {
     }
 
     fn print(&self) {
-        println!("-----");
         for m in &self.moons {
             m.print();
         }
-        println!("-----");
     }
 }
 
@@ -163,9 +161,10 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<Moon>;
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines()
             .map(|s| {
                 let (_, o) = v3_parser(s).unwrap();
@@ -177,11 +176,11 @@ impl Solver for Solution {
             }).collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut u = Universe::new(input);
         for i in 0..1000 {
             if i % 100 == 0 {
-                println!("step: {}", i);
+                log::trace!("step: {}", i);
                 u.print()
             }
             u.step();
@@ -189,7 +188,7 @@ impl Solver for Solution {
         Ok(u.moons.iter().map(|m| m.total_energy() as u64).sum())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut start = Universe::new(input);
         let mut u = start.clone();
         let mut step_count = 0;
@@ -201,15 +200,15 @@ impl Solver for Solution {
             step_count +=1;
             u.step();
 
-            if u.eqx(&start) {
+            if period_x == 0 && u.eqx(&start) {
                 period_x = step_count;
             }
 
-            if u.eqy(&start) {
+            if period_y == 0 && u.eqy(&start) {
                 period_y = step_count;
             }
 
-            if u.eqz(&start) {
+            if period_z == 0 && u.eqz(&start) {
                 period_z = step_count;
             }
             if period_x !=0 && period_y != 0 && period_z !=0 {
@@ -223,8 +222,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_12::Solution;
-    use crate::solutions::Solver;
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn e0() {
@@ -232,8 +231,7 @@ mod tests {
 <x=5, y=5, z=10>
 <x=2, y=-7, z=3>
 <x=9, y=-8, z=-3>";
-        let res = Solution::solve(input, true).unwrap();
-        dbg!(res);
+        example_test!(Solution, input, part2 = 4686774924i64);
     }
 
     #[test]
@@ -242,9 +240,7 @@ mod tests {
 <x=2, y=-10, z=-7>
 <x=4, y=-8, z=8>
 <x=3, y=5, z=-1>";
-
-        let res = Solution::solve(input, false).unwrap();
-        dbg!(res);
+        example_test!(Solution, input, part2 = 2772);
     }
 
 }
\ No newline at end of file
@@ -1,16 +1,12 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::{Program, State};
+use crate::solutions::y2019::intcode::{Program, State};
 use crate::solutions::common::Vector2;
 use std::collections::HashMap;
 use itertools::{Itertools, MinMaxResult};
-use std::io::{stdin, Write, Read};
-use termion::input::TermRead;
-use termion::raw::IntoRawMode;
+use std::io::Write;
 use std::io;
-use termion::{clear, cursor};
-use termion::event::Key;
-use std::process::exit;
+use termion::cursor;
 
 pub enum Solution {}
 
@@ -41,11 +37,9 @@ impl From<i64> for Tile {
     }
 }
 
-struct Cabinet<W: Write, R> {
+struct Cabinet<W: Write> {
     state: MemoryState,
-    save_state: Option<MemoryState>,
     stdout: W,
-    stdin: R,
     score: i64
 }
 
@@ -61,37 +55,18 @@ struct MemoryState {
     map: HashMap<Vector2, Tile>
 }
 
-impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
-    fn new(code :Program, stdin: R, stdout: W) -> Self {
+impl<W: Write> Cabinet<W> {
+    fn new(code :Program, stdout: W) -> Self {
         Cabinet {
             state: MemoryState {
                 map: HashMap::new(),
                 code
             },
-            save_state: None,
             stdout,
-            stdin,
             score: 0
         }
     }
 
-    fn save(&mut self) {
-        self.save_state = Some(self.state.clone());
-    }
-
-    fn load(&mut self) {
-        if self.save_state.is_some() {
-            self.state = self.save_state.as_ref().unwrap().clone();
-            writeln!(self.stdout,
-                     "{}{}{}{}",
-                     termion::clear::All,
-                     termion::cursor::Goto(1, 1),
-                     termion::cursor::Hide,
-                     termion::style::Reset).unwrap();
-            self.print_map();
-        }
-    }
-
     fn set_coins(&mut self) {
         self.state.code.code[0] = 2;
     }
@@ -104,50 +79,6 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
         self.state.map.iter().find(|p|*p.1 == Tile::Paddle).unwrap().0.x
     }
 
-    fn handle_input(&mut self){
-        loop {
-            let b = self.stdin.next().unwrap().unwrap();
-            match b {
-                Key::Left => {
-                    self.state.code.set_input(-1);
-                    break;
-                },
-                Key::Right => {
-                    self.state.code.set_input(1);
-                    break;
-                },
-                Key::Char('a') => {
-                    self.save();
-                    writeln!(self.stdout,
-                             "{} state saved",
-                             termion::cursor::Goto(1, 1)).unwrap();
-                    self.stdout.flush().unwrap();
-                },
-                Key::Esc => {
-                    exit(0);
-                }
-                Key::Char(' ') => {
-                    self.load();
-                    writeln!(self.stdout,
-                             "{} state loaded",
-                             termion::cursor::Goto(1, 1)).unwrap();
-                    self.stdout.flush().unwrap();
-                },
-                Key::Down => {
-                    self.state.code.set_input(0);
-                    break;
-                },
-                Key::Char('t') => {
-                    let i = i32::signum(self.get_ball_pos() - self.get_paddle_pos());
-                    self.state.code.set_input(i as i64);
-                    break;
-                }
-                _ => {}
-            };
-        }
-
-    }
-
     fn run(&mut self) -> (HashMap<Vector2, Tile>, i64){
         let mut state = OutputState::X;
         let mut x = 0;
@@ -165,7 +96,6 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
         loop {
             match self.state.code.run() {
                 State::Input => {
-                    self.print_map();
                     let i = i32::signum(self.get_ball_pos() - self.get_paddle_pos());
                     self.state.code.set_input(i as i64);
                 },
@@ -195,14 +125,7 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
                         },
                     }
                 },
-                State::Finished => {
-                    self.print_map();
-                    writeln!(self.stdout,
-                             "{}Game over",
-                             termion::cursor::Goto(1, 2)).unwrap();
-                    self.stdout.flush().unwrap();
-                    self.handle_input();
-                },
+                State::Finished => break,
                 _ => {},
             }
         }
@@ -239,7 +162,7 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
                 };
                 write!(self.stdout, "{}", c).unwrap();
             }
-            self.stdout.write(b"\n\r").unwrap();
+            self.stdout.write_all(b"\n\r").unwrap();
         }
         self.stdout.flush().unwrap();
     }
@@ -247,20 +170,15 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Cabinet<W,R> {
 
 impl Solver for Solution {
     type Input = Program;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Program::parse(input)
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, Error> {
-        let stdout = io::stdout();
-        let mut stdout = stdout.lock();
-        let stdin = io::stdin();
-        let stdin = stdin.lock();
-        let stderr = io::stderr();
-        let mut stderr = stderr.lock();
-        let mut c = Cabinet::new(input, stdin.keys(), stdout);
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        let mut c = Cabinet::new(input, io::sink());
         let (tiles,_) = c.run();
         let res = tiles.iter().filter(|(k,v)| {
             **v == Tile::Block
@@ -268,13 +186,9 @@ impl Solver for Solution {
         Ok(res as i32)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
-        let stdout = io::stdout();
-        let mut stdout = stdout;
-        let stdin = io::stdin();
-        let stdin = stdin.lock();
-        let mut stdout = stdout.into_raw_mode().unwrap();
-        let mut c = Cabinet::new(input, stdin.keys(), stdout);
+    // The game plays itself, following the ball with the paddle
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+        let mut c = Cabinet::new(input, io::sink());
         c.set_coins();
         let (_,res) = c.run();
         Ok(res as i32)
@@ -283,5 +197,47 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-
-}
\ No newline at end of file
+    use super::{Cabinet, Solution, Tile};
+    use crate::solutions::Solver;
+    use crate::solutions::common::Vector2;
+    use crate::solutions::y2019::intcode::Program;
+
+    // Draws a wall, a block, the ball and the paddle, then scores 40 times
+    // the move of the joystick plus 2.
+    const FAKE_CABINET: &str = "
+        out 0
+        out 0
+        out 1
+        out 2
+        out 0
+        out 2
+        out 3
+        out 1
+        out 4
+        out 1
+        out 2
+        out 3
+        in [move]
+        mul [move], 40, [score]
+        add [score], 2, [score]
+        out -1
+        out 0
+        out [score]
+        hlt
+    move: data 0
+    score: data 0
+    ";
+
+    #[test]
+    fn fake_cabinet() {
+        let program = Program::assemble(FAKE_CABINET).unwrap();
+        let mut cabinet = Cabinet::new(program.clone(), Vec::new());
+        let (tiles, score) = cabinet.run();
+        assert_eq!(tiles.len(), 4);
+        assert!(tiles.get(&Vector2::new(3, 1)) == Some(&Tile::Ball));
+        assert_eq!(score, 42);
+        let screen = String::from_utf8_lossy(&cabinet.stdout).into_owned();
+        assert!(screen.contains("score: 42"));
+        assert_eq!(Solution::solve_part1(program).unwrap(), 1);
+    }
+}
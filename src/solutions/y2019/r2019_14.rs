@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use itertools::Itertools;
 use nom::lib::std::collections::{HashMap, VecDeque};
@@ -85,7 +85,7 @@ fn regent_parser() {
 
 }
 
-fn parser(input: &str) -> Result<Reaction, Error> {
+fn parser(input: &str) -> Result<Reaction, AocError> {
     let (regent_str, product_str): (&str,&str) = input.split("=>").collect_tuple().unwrap();
     let (p_count, p_elem): (&str,&str) = product_str.trim().split(" ").collect_tuple().unwrap();
     let p_count = p_count.trim().parse::<i64>().unwrap();
@@ -107,21 +107,22 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<Reaction>;
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines()
             .map(|l| parser(l))
             .collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut schema = Schema::new(input);
         let res = schema.solve(1);
         Ok(res)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut schema = Schema::new(input);
         let res = schema.solve(1);
         let target:i64 = 1000000000000;
@@ -144,8 +145,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_14::Solution;
-    use crate::solutions::Solver;
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn e1() {
@@ -156,8 +157,7 @@ mod tests {
 5 B, 7 C => 1 BC
 4 C, 1 A => 1 CA
 2 AB, 3 BC, 4 CA => 1 FUEL";
-        let r = Solution::solve(input, true).unwrap();
-        dbg!(r);
+        example_test!(Solution, input, part1 = 165);
     }
 
     #[test]
@@ -171,8 +171,7 @@ mod tests {
 7 B, 7 H => 2 C
 165 ORE => 2 F
 3 B, 7 A, 5 G, 10 H => 8 D";
-        let r = Solution::solve(input, true).unwrap();
-        dbg!(r);
+        example_test!(Solution, input, part1 = 13312);
     }
 
     #[test]
@@ -194,7 +193,6 @@ mod tests {
 121 ORE => 7 J
 7 D => 6 H
 5 I, 4 J => 5 P";
-        let r = Solution::solve(input, true).unwrap();
-        dbg!(r);
+        example_test!(Solution, input, part1 = 2210736);
     }
 }
\ No newline at end of file
@@ -1,14 +1,10 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::{Program, State};
+use crate::solutions::y2019::intcode::{Program, State};
 use crate::solutions::common::{Vector2, Map2D, Cell};
 use nom::lib::std::collections::{HashMap, VecDeque, HashSet};
 use std::io::Write;
 use std::io;
-use termion::raw::IntoRawMode;
-use termion::input::TermRead;
-use termion::event::Key;
-use std::process::exit;
 use itertools::Itertools;
 
 pub enum Solution {}
@@ -80,15 +76,14 @@ impl Cell for Tile {
                 Tile::Droid => {"D"},
                 Tile::Oxy => {"O"},
             };
-        stdout.write(s.as_bytes()).unwrap();
+        stdout.write_all(s.as_bytes()).unwrap();
     }
 }
 
 #[derive(Clone)]
-struct Droid<W: Write, R> {
+struct Droid<W: Write> {
     program: Program,
     map: Map2D<Tile, W>,
-    stdin: R,
     position: Vector2
 }
 
@@ -115,14 +110,13 @@ impl Utils {
     }
 }
 
-impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Droid<W, R> {
-    fn new(program: Program, stdout: W, stdin: R) -> Self {
+impl<W: Write> Droid<W> {
+    fn new(program: Program, stdout: W) -> Self {
         let mut map = Map2D::new(stdout);
         map.data.insert(Vector2::zero(), Tile::Empty);
         Droid {
             program,
             map ,
-            stdin,
             position: Vector2::zero()
         }
     }
@@ -138,21 +132,6 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Droid<W, R> {
         }
         v
     }
-    fn get_input(&mut self) -> Move{
-        loop {
-            let b = self.stdin.next().unwrap().unwrap();
-            match b {
-                Key::Left => return Move::West,
-                Key::Right => return Move::East,
-                Key::Up => return Move::North,
-                Key::Down => return Move::South,
-                Key::Esc => {
-                    exit(0);
-                }
-                _ => {}
-            }
-        }
-    }
 
     fn get_path(&self, start: Vector2, target: Vector2) -> VecDeque<Move> {
         let mut path = HashMap::new();
@@ -260,44 +239,80 @@ impl<W: Write, R: Iterator<Item=Result<Key, std::io::Error>>> Droid<W, R> {
 
 impl Solver for Solution {
     type Input = Program;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Program::parse(input)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
-        let stdout = io::stdout();
-        //let mut stdout = stdout.into_raw_mode().unwrap();
-        let stdin = io::stdin();
-        let mut d = Droid::new(input, stdout, stdin.keys());
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        let mut d = Droid::new(input, io::sink());
         d.run();
         let pos = d.map.data.iter().find(|&(v, t)|*t == Tile::Oxy).unwrap();
         let p = d.get_path(Vector2::zero(), *pos.0);
         Ok(p.len() as i32)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
-        let stdout = io::stdout();
-        //let mut stdout = stdout.into_raw_mode().unwrap();
-        let stdin = io::stdin();
-        let mut d = Droid::new(input, stdout, stdin.keys());
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+        let mut d = Droid::new(input, io::sink());
         d.run();
         let (oxy_pos, _) = d.map.data.iter().find(|&(v, t)|*t == Tile::Oxy).unwrap();
         let all_pos = d.map.data.iter()
             .filter(|&(v, t)| *t == Tile::Empty)
             .map(|(v, t)| *v).collect_vec();
-        dbg!(all_pos.len());
-        let max_dist = all_pos.iter().map(|p| {
-            let l = d.get_path(*oxy_pos, *p).len();
-            dbg!(l);
-            l
-        }).max().unwrap();
+        log::debug!("{} open positions", all_pos.len());
+        let max_dist = all_pos.iter().map(|p| d.get_path(*oxy_pos, *p).len()).max().unwrap();
         Ok(max_dist as i32)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{Droid, Tile};
+    use crate::solutions::common::Vector2;
+    use crate::solutions::y2019::intcode::Program;
 
+    // A corridor from x = 0 to the oxygen system at x = 2, walled all around.
+    const FAKE_DROID: &str = "
+    loop:
+        in [move]
+        eq [move], 3, [t]
+        jnz [t], west
+        eq [move], 4, [t]
+        jnz [t], east
+    wall:
+        out 0
+        jz 0, loop
+    west:
+        add [x], -1, [next]
+        jz 0, check
+    east:
+        add [x], 1, [next]
+    check:
+        lt [next], 0, [t]
+        jnz [t], wall
+        lt 2, [next], [t]
+        jnz [t], wall
+        add [next], 0, [x]
+        eq [x], 2, [t]
+        add [t], 1, [t]
+        out [t]
+        jz 0, loop
+    move: data 0
+    t: data 0
+    x: data 0
+    next: data 0
+    ";
+
+    #[test]
+    fn fake_droid() {
+        let program = Program::assemble(FAKE_DROID).unwrap();
+        let mut droid = Droid::new(program, Vec::new());
+        droid.run();
+        let oxygen = Vector2::new(2, 0);
+        assert_eq!(droid.map.data.get(&oxygen), Some(&Tile::Oxy));
+        assert_eq!(droid.map.data.get(&Vector2::new(-1, 0)), Some(&Tile::Wall));
+        assert_eq!(droid.get_path(Vector2::zero(), oxygen).len(), 2);
+    }
 }
\ No newline at end of file
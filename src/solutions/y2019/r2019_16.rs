@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use itertools::Itertools;
 
@@ -49,7 +49,7 @@ impl Solution {
 
     fn phase_power(input:Vec<i32>, n: usize) -> Vec<i32> {
         let m = Solution::power_matrix(Solution::build_matrix(input.len()), n);
-        println!("matrix built");
+        log::debug!("matrix built");
         let mut res = vec![];
         res.reserve_exact(input.len());
         for i in 0..input.len() {
@@ -86,13 +86,14 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<i32>;
-    type Output = String;
+    type Output1 = String;
+    type Output2 = String;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Ok(input.chars().into_iter().filter_map(|c| c.to_digit(10).map(|i| i as i32)).collect_vec())
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut res = input;
         for _ in 0..100 {
             res = Solution::phase(res);
@@ -101,7 +102,7 @@ impl Solver for Solution {
         Ok(res.iter().take(8).join("").to_string())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let input = input.repeat(10000);
         let mut res= input.clone();
         let skip: usize = input[0..7].iter().join("").parse().unwrap();
@@ -116,42 +117,29 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_16::Solution;
+    use super::Solution;
     use itertools::Itertools;
 
     #[test]
     fn e1() {
         let i0 = vec![1,2,3,4,5,6,7,8];
         let i1 = Solution::phase(i0);
-        dbg!(i1.iter().join(""));
+        assert_eq!(i1.iter().join(""), "48226158");
     }
 
     #[test]
     fn e1bis() {
         let i0 = vec![1,2,3,4,5,6,7,8];
         let i1 = Solution::phase_power(i0, 1);
-        dbg!(i1.iter().join(""));
+        assert_eq!(i1.iter().join(""), "48226158");
     }
 
     #[test]
     fn e2() {
-        let m = Solution::build_matrix(18);
-        for i in 0..m.len() {
-            for j in 0..m[0].len() {
-                print!("{:3}",m[i][j]);
-            }
-            println!()
-        }
-    }
-
-    #[test]
-    fn e3() {
-        let m = Solution::power_matrix(Solution::build_matrix(8), 10);
-        for i in 0..m.len() {
-            for j in 0..m[0].len() {
-                print!("{:3}",m[i][j]);
-            }
-            println!()
-        }
+        let m = Solution::build_matrix(8);
+        assert_eq!(m[0], [1, 0, -1, 0, 1, 0, -1, 0]);
+        assert_eq!(m[1], [0, 1, 1, 0, 0, -1, -1, 0]);
+        assert_eq!(m[2], [0, 0, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(m[7], [0, 0, 0, 0, 0, 0, 0, 1]);
     }
-}
\ No newline at end of file
+}
@@ -1,11 +1,9 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::{Program, State};
+use crate::solutions::y2019::intcode::{Program, State};
 use crate::solutions::common::{Map2D, Cell, Direction, Vector2};
 use std::io::Write;
 use std::io;
-use termion::raw::IntoRawMode;
-use termion::input::TermRead;
 
 pub enum Solution {}
 
@@ -40,7 +38,7 @@ impl Cell for Tile {
     fn print<W: Write>(&self, stdout: &mut W) {
         match self {
             Tile::Ascii(c) => {
-                stdout.write(&[*c]);
+                stdout.write_all(&[*c]).unwrap();
             },
         }
     }
@@ -113,14 +111,15 @@ impl<W: Write> Robot<W> {
 
 impl Solver for Solution {
     type Input = Program;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Program::parse(input)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
-        let mut r = Robot::new(input, io::stdout());
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        let mut r = Robot::new(input, io::sink());
         r.run();
         let align = r.map.data.iter().filter(|(pos, t)| {
             t.is_scalfold() && pos.neigh().iter().all(|p| {
@@ -135,7 +134,7 @@ impl Solver for Solution {
         Ok(align)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let a = b"R12L8L4L4L8R6L6\n";
         let b = b"R6R6L8R4R4R8R12\n";
         let c = b"L6R6L6R6\n";
@@ -144,13 +143,7 @@ impl Solver for Solution {
         let f = b"R12L6L4R12L8L4L4L8L4\n";
         let g = b"R12L6L4L8R6L6\n";
         let input_t = b"AARBCDEDRR4FFG";
-        let stderr = io::stderr();
-        let stderr = stderr.lock();
-        let mut stdout = io::stdout();
-        let stdout = stdout.lock();
-        let stdout = stdout.into_raw_mode().unwrap();
-
-        let mut r = Robot::new(input, stdout);
+        let mut r = Robot::new(input, io::sink());
         r.p.code[0] = 2;
         r.set_path("A,B,A,B,C,A,C,A,C,B\nR,12,L,8,L,4,L,4\nL,8,R,6,L,6\nL,8,L,4,R,12,L,6,L,4\ny\n");
         let result = r.run();
@@ -1,10 +1,11 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use crate::solutions::common::Vector2;
+use crate::solutions::progress::Progress;
 use itertools::Itertools;
 use nom::lib::std::collections::{HashMap, VecDeque, HashSet};
 use std::fmt::Display;
-use failure::_core::fmt::Formatter;
+use std::fmt::Formatter;
 use std::sync::{Arc, RwLock};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -182,12 +183,9 @@ impl Map {
 
     fn run(&self, start: &Vector2) -> i32 {
         let mut path = self.batch_brute(*start);
-        path.iter().rev().for_each(|t| {
-            print!("{} ", *t);
-        });
-        println!();
+        log::debug!("{}", path.iter().rev().join(" "));
         let mut min_score = self.compute_path(*start, path.clone()).unwrap();
-        println!("Before optim: {}", min_score);
+        log::debug!("Before optim: {}", min_score);
         for i in 0..path.len() {
             for j in 0..path.len() {
                 if i <= j {
@@ -203,14 +201,12 @@ impl Map {
                 }
             }
         }
-        path.iter().rev().for_each(|t| {
-            print!("{} ", *t);
-        });
-        println!();
+        log::debug!("{}", path.iter().rev().join(" "));
         self.compute_path(*start, path).unwrap()
     }
 
-    fn run_multi_brute(&self, robot_pos: Vec<Vector2>, keys: Vec<Tile>) -> i32 {
+    fn run_multi_brute(&self, robot_pos: Vec<Vector2>, keys: Vec<Tile>, progress: &mut Progress) -> i32 {
+        progress.inc(1);
         robot_pos.iter().enumerate()
             .filter(|(i,p)| {
                 self.possible_targets(p, keys.clone()).len() > 0
@@ -223,7 +219,7 @@ impl Map {
                 new_keys.push(k);
             }
             new_all_pos[i] = new_p;
-            d + self.run_multi_brute(new_all_pos, new_keys)
+            d + self.run_multi_brute(new_all_pos, new_keys, progress)
         }).min().unwrap_or(0)
     }
 
@@ -232,12 +228,9 @@ impl Map {
         let mut brute_seq = vec![8,8];
         loop {
             let (_, new_pos, mut small_path) = self.run_brute(&pos, keys.clone(), brute_seq.pop());
-            small_path.iter().rev().for_each(|t| {
-                print!("{} ", *t);
-                keys.push(*t);
-            });
+            log::debug!("{}", small_path.iter().rev().join(" "));
+            keys.extend(small_path.iter().rev());
             pos = new_pos;
-            println!();
             if small_path.len() == 0 {
                 break
             }
@@ -294,22 +287,23 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Map;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let m = input.lines()
             .map(|l| l.chars().map(Tile::from_char).collect_vec())
             .collect_vec();
         Ok(Map::new(m))
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(mut input: Self::Input) -> Result<Self::Output1, AocError> {
         let (start_pos, _) = input.all_tiles().into_iter().find(|&(v, t)| t == Tile::Start).unwrap();
         let res = input.run(&start_pos);
         Ok(res)
     }
 
-    fn solve_part2(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(mut input: Self::Input) -> Result<Self::Output2, AocError> {
         let (start_pos, _) = input.all_tiles().into_iter().find(|&(v, t)| t == Tile::Start).unwrap();
         input.set(&start_pos, Tile::Wall);
         input.set(&start_pos.add(&Vector2::new(0,1)), Tile::Wall);
@@ -326,7 +320,7 @@ impl Solver for Solution {
         input.set(&start4, Tile::Start);
 
         let mut rpos = vec![start1, start2, start3, start4];
-        let mut total_d = input.run_multi_brute(rpos, vec![]);
+        let mut total_d = input.run_multi_brute(rpos, vec![], &mut Progress::new("branches", None));
         Ok(total_d)
     }
 }
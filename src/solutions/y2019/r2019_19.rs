@@ -1,12 +1,12 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::Program;
+use crate::solutions::y2019::intcode::Program;
 
 pub enum Solution {}
 
 impl Solution {
     fn is_tracker(input: &Program, x: i64, y: i64) -> bool {
-        let mut o = input.clone().run_with_input(vec![y,x]);
+        let mut o = input.clone().run_with_input(vec![x,y]);
         if let Some(o) = o.pop() {
             if o==1 {
                return true;
@@ -24,17 +24,18 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Program;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Program::parse(input)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut count = 0;
         for y in 0..50 {
             for x in 0..50 {
-                let mut o = input.clone().run_with_input(vec![y,x]);
+                let mut o = input.clone().run_with_input(vec![x,y]);
                 if let Some(o) = o.pop() {
                     if o == 1 {
                         count +=1
@@ -46,13 +47,13 @@ impl Solver for Solution {
         Ok(count)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut min_x = 0;
         let mut y = 800;
         loop {
             let mut wide = 0;
             let mut x = min_x;
-            println!("y: {}, x:{}", y, x);
+            log::trace!("y: {}, x:{}", y, x);
             loop {
                 x+=1;
                 if Solution::is_tracker(&input, x, y) {
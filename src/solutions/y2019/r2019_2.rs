@@ -1,69 +1,56 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
+use crate::solutions::y2019::intcode::Program;
 
 pub enum Solution {}
 
 impl Solution {
-    fn run(input: &[i32]) -> i32{
-        let mut input = input.to_vec();
-        let mut pc = 0;
-        loop {
-            let op_code = input[pc];
-            match op_code {
-                1 => {
-                    let v1 = input[pc+1] as usize;
-                    let v2 = input[pc+2] as usize;
-                    let oi = input[pc+3] as usize;
-                    pc+=4;
-                    input[oi] = input[v1] + input[v2];
-                },
-                2 => {
-                    let v1 = input[pc+1] as usize;
-                    let v2 = input[pc+2] as usize;
-                    let oi = input[pc+3] as usize;
-                    pc+=4;
-                    input[oi] = input[v1] * input[v2];
-                }
-                99 => {
-                    break
-                }
-                _ => {unimplemented!()}
-            }
-        }
-        input[0]
+    fn run(input: &Program, noun: i64, verb: i64) -> i64 {
+        let mut program = input.clone();
+        program.code[1] = noun;
+        program.code[2] = verb;
+        program.run();
+        program.code[0]
     }
 }
 
 impl Solver for Solution {
-    type Input = Vec<i32>;
-    type Output = i32;
+    type Input = Program;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
-        input.trim_end()
-            .split(',')
-            .filter(|s|!s.is_empty())
-            .map(|u|u.parse().map_err(From::from))
-            .collect()
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
+        Program::parse(input)
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, Error> {
-        input[1] = 12;
-        input[2] = 2;
-        Ok(Solution::run(&input))
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(Solution::run(&input, 12, 2))
     }
 
-    fn solve_part2(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let target = 19690720;
         for verb in 0..100 {
             for noun in 0..100 {
-                input[1] = noun;
-                input[2] = verb;
-                let res = Solution::run(&input);
+                let res = Solution::run(&input, noun, verb);
                 if res == target {
                     return Ok(100 * noun + verb);
                 }
             }
         }
-        Ok(0)
+        Err(AocError::Unknown(format!("no noun and verb give {}", target)))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::solutions::y2019::intcode::Program;
+    use crate::solutions::{AocError, Solver};
+
+    #[test]
+    fn no_answer() {
+        let program = Program::parse("99,0,0,0").unwrap();
+        let err = Solution::solve_part2(program).unwrap_err();
+        assert!(matches!(err, AocError::Unknown(m) if m == "no noun and verb give 19690720"));
+    }
+}
@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use std::collections::HashMap;
 use crate::solutions::common::Vector2;
@@ -57,7 +57,7 @@ impl Map {
                 continue
             }
             if debug {
-                println!("{}, {}, ({},{})", d, level, pos.x, pos.y);
+                log::trace!("{}, {}, ({},{})", d, level, pos.x, pos.y);
             }
             let level_pos = (level, pos);
             if visited.contains(&level_pos) {
@@ -87,12 +87,12 @@ impl Map {
                         if n == *p1 {
                             n = p2.clone();
                             if debug {
-                                println!("warp: {} {} {} ({},{}) -> ({},{})", w, next_d, next_level, p1.x, p1.y, p2.x, p2.y)
+                                log::trace!("warp: {} {} {} ({},{}) -> ({},{})", w, next_d, next_level, p1.x, p1.y, p2.x, p2.y)
                             }
                         } else {
                             n = p1.clone();
                             if debug {
-                                println!("warp: {} {} {} ({},{}) -> ({},{})", w, next_d, next_level, p2.x, p2.y, p1.x, p1.y);
+                                log::trace!("warp: {} {} {} ({},{}) -> ({},{})", w, next_d, next_level, p2.x, p2.y, p1.x, p1.y);
                             }
                         }
                     },
@@ -139,9 +139,10 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Map;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut data = input.lines().map(|l|
             l.chars().map(|c| {
                 match c {
@@ -205,9 +206,7 @@ impl Solver for Solution {
         }
 
         named.iter().for_each(|(s, (p1, p2))| {
-            dbg!(s);
-            dbg!(p1, &data[p1.y as usize][p1.x as usize]);
-            dbg!(p2, &data[p2.y as usize][p2.x as usize]);
+            log::trace!("warp {}: {:?} {:?}", s, p1, p2);
             data[p1.y as usize][p1.x as usize] = Tile::Warp(s.clone());
             data[p2.y as usize][p2.x as usize] = Tile::Warp(s.clone());
         });
@@ -218,20 +217,19 @@ impl Solver for Solution {
         })
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
-        dbg!(&input.not_connected);
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        log::debug!("not connected: {:?}", input.not_connected);
         let start = input.not_connected["AA"];
         let end = input.not_connected["ZZ"];
         let res = input.run(start, end, false, false);
         Ok(res)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
-        dbg!(&input.not_connected);
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+        log::debug!("not connected: {:?}", input.not_connected);
         let start = input.not_connected["AA"];
         let end = input.not_connected["ZZ"];
-        dbg!(input.is_inner_warp(&start));
-        dbg!(input.is_inner_warp(&end));
+        log::debug!("inner start: {}, inner end: {}", input.is_inner_warp(&start), input.is_inner_warp(&end));
         let res = input.run(start, end, true, false);
         Ok(res)
     }
@@ -239,8 +237,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_20::Solution;
-    use crate::solutions::Solver;
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn e1() {
@@ -264,7 +262,6 @@ FG..#########.....#
              Z    #
              Z    #
 ";
-        let r = Solution::solve(i, true);
-        dbg!(r);
+        example_test!(Solution, i, part1 = 23);
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use std::collections::HashSet;
 use std::iter::FromIterator;
@@ -42,7 +42,7 @@ impl Move {
     fn new(dir: Direction, step: i32) -> Move {
         Move {dir, step}
     }
-    fn from_string(input: &str) -> Result<Move, Error>{
+    fn from_string(input: &str) -> Result<Move, AocError>{
         Ok(Move::new(
             Direction::from_char(input.chars().nth(0).unwrap()),
             input[1..].parse()?,
@@ -97,22 +97,23 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = (Vec<Move>, Vec<Move>);
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut wires:Vec<Vec<&str>> =
             input
                 .lines()
                 .map(|l| l.split(',').collect())
                 .collect();
 
-        let w0:Result<Vec<Move>, Error> = wires[0].iter().map(|l|Move::from_string(l)).collect();
-        let w1:Result<Vec<Move>, Error> = wires[1].iter().map(|l|Move::from_string(l)).collect();
+        let w0:Result<Vec<Move>, AocError> = wires[0].iter().map(|l|Move::from_string(l)).collect();
+        let w1:Result<Vec<Move>, AocError> = wires[1].iter().map(|l|Move::from_string(l)).collect();
         assert_eq!(2, wires.len());
         Ok((w0?, w1?))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let (w0, w1) = input;
         let p0 = Solution::compute_path(w0);
         let p1 = Solution::compute_path(w1);
@@ -124,7 +125,7 @@ impl Solver for Solution {
             .map(|p|p.len()).min().unwrap() as i32)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let (w0, w1) = input;
         let p0 = Solution::compute_path(w0);
         let p1 = Solution::compute_path(w1);
@@ -142,18 +143,18 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_3::Solution;
-    use crate::solutions::Solver;
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn e0() {
         let str_v = "R8,U5,L5,D3\nU7,R6,D4,L4";
-        dbg!(Solution::solve(str_v, true));
+        example_test!(Solution, str_v, part1 = 6, part2 = 30);
     }
 
     #[test]
     fn e1() {
         let str_v = "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83";
-        dbg!(Solution::solve(str_v, true));
+        example_test!(Solution, str_v, part1 = 159, part2 = 610);
     }
 }
\ No newline at end of file
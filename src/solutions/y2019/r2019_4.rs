@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use std::collections::HashMap;
 
@@ -51,23 +51,24 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = (i32, i32);
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
-        let r: Result<Vec<i32>, Error> =
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
+        let r: Result<Vec<i32>, AocError> =
             input.trim_end().split("-")
             .map(|s|s.parse::<i32>().map_err(From::from)).collect();
         let r = r?;
         Ok((r[0], r[1]))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let (low, high) = input;
         let res = (low..=high).filter(|&d|Solution::is_valid(d, true)).count();
         Ok(res as i32)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let (low, high) = input;
         let res = (low..=high).filter(|&d|Solution::is_valid(d, false)).count();
         Ok(res as i32)
@@ -76,7 +77,7 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_4::Solution;
+    use super::Solution;
 
     #[test]
     fn is_valid_e1() {
@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use std::collections::HashMap;
 
@@ -33,9 +33,10 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = (HashMap<String, Vec<String>>, HashMap<String, String>);
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut map = HashMap::new();
         let mut inverse_map = HashMap::new();
         input.lines()
@@ -53,7 +54,7 @@ impl Solver for Solution {
         Ok((map, inverse_map))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let (map, inverse_map) = input;
         let nodes: Vec<String> = map.iter().flat_map(|(key, values)| values.clone()).collect();
         let mut c = 0;
@@ -65,7 +66,7 @@ impl Solver for Solution {
         Ok(c as i32)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let (map, inverse_map) = input;
 
         let mut path_you = Solution::path_to_root(&String::from("YOU"), &inverse_map);
@@ -84,8 +85,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_6::*;
-    use crate::solutions::Solver;
+    use super::*;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn e1() {
@@ -100,8 +101,7 @@ D)I
 E)J
 J)K
 K)L";
-        let res = Solution::solve(i, true).unwrap();
-        println!("{}", res);
+        example_test!(Solution, i, part1 = 42);
     }
 
     #[test]
@@ -119,7 +119,6 @@ J)K
 K)L
 K)YOU
 I)SAN";
-        let res = Solution::solve(i, false).unwrap();
-        println!("{}", res);
+        example_test!(Solution, i, part1 = 54, part2 = 4);
     }
 }
\ No newline at end of file
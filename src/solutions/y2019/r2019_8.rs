@@ -1,4 +1,4 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
 use itertools::Itertools;
 
@@ -20,9 +20,10 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<Image>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = String;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let w = 25;
         let h = 6;
         let datalength = w * h;
@@ -37,14 +38,14 @@ impl Solver for Solution {
         Ok(res)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let l = input.iter().min_by_key(|a|a.data.iter().filter(|&&d|d == 0).count()).unwrap();
         let c1 = l.data.iter().filter(|&&a| a == 1).count();
         let c2 =  l.data.iter().filter(|&&a| a == 2).count();
         Ok(c1 * c2)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let w = 25;
         let h = 6;
         let datalength = w * h;
@@ -60,12 +61,11 @@ impl Solver for Solution {
             }
         }
 
-        let rs = res.iter().map(|d| d.to_string()).join("");
-        for hi in 0..h {
-            let s = &rs[hi*w..(hi+1)*w];
-            println!("{}", s);
-        }
-        Ok(0)
+        let rows = res
+            .chunks(w)
+            .map(|row| row.iter().map(|&p| if p == 1 { '#' } else { '.' }).collect::<String>())
+            .join("\n");
+        Ok(rows)
     }
 }
 
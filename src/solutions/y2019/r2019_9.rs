@@ -1,14 +1,15 @@
-use failure::Error;
+use crate::solutions::AocError;
 use crate::solutions::Solver;
-use crate::solutions::intcode::Program;
+use crate::solutions::y2019::intcode::Program;
 
 pub enum Solution {}
 
 impl Solver for Solution {
     type Input = Vec<i64>;
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    fn parse_input(input: &str) -> Result<Self::Input, Error> {
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.trim_end()
             .split(',')
             .filter(|s|!s.is_empty())
@@ -16,31 +17,31 @@ impl Solver for Solution {
             .collect()
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, Error> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let res = Program::new(input).run_with_input(vec!(1));
-        dbg!(&res);
+        log::debug!("outputs {:?}", res);
         Ok(*res.last().unwrap())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, Error> {
-        let res = Program::new(input).run_with_input(vec!(1));
-        dbg!(&res);
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+        let res = Program::new(input).run_with_input(vec!(2));
+        log::debug!("outputs {:?}", res);
         Ok(*res.last().unwrap())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::r2019_9::Solution;
+    use super::Solution;
     use crate::solutions::Solver;
-    use crate::solutions::intcode::Program;
+    use crate::solutions::y2019::intcode::Program;
 
     #[test]
     fn e1() {
         let input = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
         let mut code = Solution::parse_input(input).unwrap();
         let res = Program::new(code).run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99]);
     }
 
     #[test]
@@ -49,7 +50,7 @@ mod tests {
         let mut code = Solution::parse_input(input).unwrap();
         code.resize(200, 0);
         let res = Program::new(code).run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [1125899906842624]);
     }
 
     #[test]
@@ -58,6 +59,6 @@ mod tests {
         let mut code = Solution::parse_input(input).unwrap();
         code.resize(200, 0);
         let res = Program::new(code).run_with_input(vec!());
-        dbg!(res);
+        assert_eq!(res, [1219070632396864]);
     }
 }
\ No newline at end of file
@@ -7,7 +7,8 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<i32>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines()
@@ -15,7 +16,7 @@ impl Solver for Solution {
             .collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
        let mut first = input[0];
 
        let mut count = 0;
@@ -28,7 +29,7 @@ impl Solver for Solution {
        Ok(count)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut count = 0;
         let mut s = None;
         for w in input.windows(3) {
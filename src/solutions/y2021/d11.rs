@@ -8,17 +8,18 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<i32>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         unimplemented!()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         unimplemented!()
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         unimplemented!()
     }
 }
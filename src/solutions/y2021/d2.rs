@@ -68,13 +68,14 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<Command>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         input.lines().map(Solution::parse_line).collect()
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut sub = Submarine {
             depth: 0,
             aim: 0,
@@ -87,7 +88,7 @@ impl Solver for Solution {
         Ok(sub.depth * sub.position)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut sub = Submarine {
             depth: 0,
             aim: 0,
@@ -1,25 +1,30 @@
-pub mod d1;
-pub mod d2;
-pub mod d3;
-pub mod d4;
-pub mod d5;
-pub mod d6;
-pub mod d7;
-pub mod d8;
-pub mod d9;
-pub mod d10;
-pub mod d11;
-pub mod d12;
-pub mod d13;
-pub mod d14;
-pub mod d15;
-pub mod d16;
-pub mod d17;
-pub mod d18;
-pub mod d19;
-pub mod d20;
-pub mod d21;
-pub mod d22;
-pub mod d23;
-pub mod d24;
-pub mod d25;
\ No newline at end of file
+use crate::solutions::registry::days;
+
+days! {
+    2021;
+    1 => d1, "Sonar Sweep", [1, 2];
+    2 => d2, "Dive!", [1, 2];
+    3 => d3, "Binary Diagnostic", [];
+    4 => d4, "Giant Squid", [];
+    5 => d5, "Hydrothermal Venture", [];
+    6 => d6, "Lanternfish", [];
+    7 => d7, "The Treachery of Whales", [];
+    8 => d8, "Seven Segment Search", [];
+    9 => d9, "Smoke Basin", [];
+    10 => d10, "Syntax Scoring", [];
+    11 => d11, "Dumbo Octopus", [];
+    12 => d12, "Passage Pathing", [];
+    13 => d13, "Transparent Origami", [];
+    14 => d14, "Extended Polymerization", [];
+    15 => d15, "Chiton", [];
+    16 => d16, "Packet Decoder", [];
+    17 => d17, "Trick Shot", [];
+    18 => d18, "Snailfish", [];
+    19 => d19, "Beacon Scanner", [];
+    20 => d20, "Trench Map", [];
+    21 => d21, "Dirac Dice", [];
+    22 => d22, "Reactor Reboot", [];
+    23 => d23, "Amphipod", [];
+    24 => d24, "Arithmetic Logic Unit", [];
+    25 => d25, "Sea Cucumber", [];
+}
@@ -49,26 +49,27 @@ impl Ma {
 
 impl Solver for Solution {
     type Input = Vec<String>;
-    type Output = u32;
+    type Output1 = u32;
+    type Output2 = u32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Ok(input.lines().map(|l| l.into()).collect_vec())
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let r = input
             .iter()
             .map(|l| {
                 let s2 = l.chars().filter_map(|c| c.to_digit(10)).collect_vec();
                 let d = s2.first().unwrap() * 10 + s2.last().unwrap();
-                println!("{}", &d);
+                log::trace!("{}", &d);
                 d
             })
             .sum();
         Ok(r)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let r = input
             .iter()
             .map(|l| {
@@ -125,7 +126,7 @@ impl Solver for Solution {
                     }
                 }
                 let d = first.unwrap() * 10 + last.unwrap();
-                println!("{}", d);
+                log::trace!("{}", d);
                 d
             })
             .sum();
@@ -137,7 +138,7 @@ impl Solver for Solution {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use crate::solutions::Solver;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn test1() {
@@ -149,17 +150,15 @@ xtwone3four
 zoneight234
 7pqrstsixteen
 ";
-        let ab = Solution::parse_input(i).unwrap();
-        let r = Solution::solve_part2(ab).unwrap();
-        assert_eq!(r, 281);
+
+        example_test!(Solution, i, part2 = 281);
     }
 
     #[test]
     fn test2() {
         let i = "three7pktwo4279z
 ";
-        let ab = Solution::parse_input(i).unwrap();
-        let r = Solution::solve_part2(ab).unwrap();
-        assert_eq!(r, 39)
+
+        example_test!(Solution, i, part1 = 79, part2 = 39);
     }
 }
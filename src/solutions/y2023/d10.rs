@@ -53,7 +53,8 @@ pub struct Pos {
 
 impl Solver for Solution {
     type Input = (Pos, Vec<Vec<Elem>>);
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut map = vec![];
@@ -82,7 +83,7 @@ impl Solver for Solution {
         Ok((pos, map))
     }
 
-    fn solve_part1((start, map): Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1((start, map): Self::Input) -> Result<Self::Output1, AocError> {
         let mut maxd = 0;
         let mut stack = vec![start];
         let mut dist = HashMap::new();
@@ -140,7 +141,7 @@ impl Solver for Solution {
         Ok(maxd)
     }
 
-    fn solve_part2((start, map): Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2((start, map): Self::Input) -> Result<Self::Output2, AocError> {
         let mut stack: Vec<(Option<Pos>, Pos)> = vec![(None, start)];
         let mut path = vec![];
         path.push(start);
@@ -189,14 +190,14 @@ impl Solver for Solution {
             }
         }
 
-        println!(
+        log::debug!(
             "{}, {}, {}, {}",
             path.len(),
             pred.len(),
             loopElem.len(),
             next.len()
         );
-        println!("DFS done");
+        log::debug!("DFS done");
 
         let mut em = vec![];
         for i in 0..map.len() {
@@ -227,9 +228,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d10() {
@@ -239,17 +239,12 @@ SJ.L7
 |F--J
 LJ...
 ";
-        dbg!(Solution::solve(i, true));
+
+        example_test!(Solution, i, part1 = 8);
     }
 
     #[test]
     fn d10_2() {
-        let i = "..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...
-";
         let i2 = "...........
 .S-------7.
 .|F-----7|.
@@ -260,7 +255,6 @@ LJ...
 .L--J.L--J.
 ...........
 ";
-
         let i3 = "..........
 .S------7.
 .|F----7|.
@@ -270,7 +264,6 @@ LJ...
 .|II||II|.
 .L--JL--J.
 ..........";
-
         let i4 = "FF7FSF7F7F7F7F7F---7
 L|LJ||||||||||||F--J
 FL-7LJLJ||||||LJL-77
@@ -281,7 +274,6 @@ L---JF-JLJ.||-FJLJJ7
 7-L-JL7||F7|L7F-7F7|
 L.L7LFJ|||||FJL7||LJ
 L7JLJL-JLJLJL--JLJ.L";
-
         let i5 = ".F----7F7F7F7F-7....
 .|F--7||||||||FJ....
 .||.FJ||||||||L7....
@@ -293,10 +285,9 @@ L--J.L7...LJS7F-7L7.
 ....FJL-7.||.||||...
 ....L---J.LJ.LJLJ...";
 
-        assert_eq!("10", Solution::solve(i4, false).unwrap());
-        assert_eq!("4", Solution::solve(i3, false).unwrap());
-        assert_eq!("4", Solution::solve(i2, false).unwrap());
-
-        assert_eq!("8", Solution::solve(i5, false).unwrap());
+        example_test!(Solution, i4, part2 = 10);
+        example_test!(Solution, i3, part2 = 4);
+        example_test!(Solution, i2, part2 = 4);
+        example_test!(Solution, i5, part2 = 8);
     }
 }
@@ -9,42 +9,10 @@ use crate::solutions::AocError;
 
 pub enum Solution {}
 
-impl Solution {}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum Elem {
-    Empty,
-    Galaxy,
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct Pos {
-    x: i32,
-    y: i32,
-}
-
-impl Solver for Solution {
-    type Input = Vec<Vec<Elem>>;
-    type Output = i64;
-
-    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
-        Ok(input
-            .lines()
-            .map(|l| {
-                l.chars()
-                    .filter_map(|c| {
-                        return match c {
-                            '.' => Some(Elem::Empty),
-                            '#' => Some(Elem::Galaxy),
-                            _ => None,
-                        };
-                    })
-                    .collect_vec()
-            })
-            .collect_vec())
-    }
-
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+impl Solution {
+    // Sum of the distances between galaxies, each empty row and column
+    // growing `factor` times.
+    fn distances(input: &[Vec<Elem>], factor: i64) -> i64 {
         let mut r = HashSet::new();
         for i in 0..input.len() {
             let mut found = true;
@@ -74,8 +42,8 @@ impl Solver for Solution {
             }
         }
 
-        println!("rows: {:?}", r);
-        println!("cols: {:?}", c);
+        log::debug!("rows: {:?}", r);
+        log::debug!("cols: {:?}", c);
 
         let mut galaxies = vec![];
         for i in 0..input[0].len() {
@@ -91,7 +59,7 @@ impl Solver for Solution {
 
         let countcombi = (galaxies.len() - 1) * galaxies.len() / 2;
 
-        println!("{} => {}", galaxies.len(), countcombi);
+        log::debug!("{} => {}", galaxies.len(), countcombi);
 
         let mut sum = 0;
         for i in 0..galaxies.len() {
@@ -103,22 +71,62 @@ impl Solver for Solution {
                 let miny = g.y.min(h.y);
                 let maxy = g.y.max(h.y);
                 let dist = maxx - minx + maxy - miny;
-                let count_rows = c.iter().filter(|&&ri| ri > minx && ri < maxx).count() as i32;
-                let count_cols = r.iter().filter(|&&ci| ci > miny && ci < maxy).count() as i32;
+                let count_rows = c.iter().filter(|&&ri| ri > minx && ri < maxx).count() as i64;
+                let count_cols = r.iter().filter(|&&ci| ci > miny && ci < maxy).count() as i64;
 
-                let fd = dist + count_rows + count_cols;
-                /*
-                println!(
+                let fd = dist as i64 + count_rows * (factor - 1) + count_cols * (factor - 1);
+
+                log::trace!(
                     "dist {:?} to {:?} => {} ({}, {}, {})",
-                    g, h, fd, dist, count_rows, count_cols
-                );*/
+                    g,
+                    h,
+                    fd,
+                    dist,
+                    count_rows,
+                    count_cols
+                );
                 sum += fd;
             }
         }
-        Ok(sum.into())
+        sum
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Elem {
+    Empty,
+    Galaxy,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl Solver for Solution {
+    type Input = Vec<Vec<Elem>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .filter_map(|c| {
+                        return match c {
+                            '.' => Some(Elem::Empty),
+                            '#' => Some(Elem::Galaxy),
+                            _ => None,
+                        };
+                    })
+                    .collect_vec()
+            })
+            .collect_vec())
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut r = HashSet::new();
         for i in 0..input.len() {
             let mut found = true;
@@ -148,8 +156,8 @@ impl Solver for Solution {
             }
         }
 
-        println!("rows: {:?}", r);
-        println!("cols: {:?}", c);
+        log::debug!("rows: {:?}", r);
+        log::debug!("cols: {:?}", c);
 
         let mut galaxies = vec![];
         for i in 0..input[0].len() {
@@ -165,10 +173,9 @@ impl Solver for Solution {
 
         let countcombi = (galaxies.len() - 1) * galaxies.len() / 2;
 
-        println!("{} => {}", galaxies.len(), countcombi);
+        log::debug!("{} => {}", galaxies.len(), countcombi);
 
         let mut sum = 0;
-        let factor = 1000_000;
         for i in 0..galaxies.len() {
             for j in (i + 1)..galaxies.len() {
                 let g = galaxies[i];
@@ -178,27 +185,31 @@ impl Solver for Solution {
                 let miny = g.y.min(h.y);
                 let maxy = g.y.max(h.y);
                 let dist = maxx - minx + maxy - miny;
-                let count_rows = c.iter().filter(|&&ri| ri > minx && ri < maxx).count() as i64;
-                let count_cols = r.iter().filter(|&&ci| ci > miny && ci < maxy).count() as i64;
-
-                let fd = dist as i64 + count_rows * (factor - 1) + count_cols * (factor - 1);
+                let count_rows = c.iter().filter(|&&ri| ri > minx && ri < maxx).count() as i32;
+                let count_cols = r.iter().filter(|&&ci| ci > miny && ci < maxy).count() as i32;
 
-                println!(
+                let fd = dist + count_rows + count_cols;
+                /*
+                log::trace!(
                     "dist {:?} to {:?} => {} ({}, {}, {})",
                     g, h, fd, dist, count_rows, count_cols
-                );
+                );*/
                 sum += fd;
             }
         }
-        Ok(sum)
+        Ok(sum.into())
+    }
+
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
+        Ok(Solution::distances(&input, 1_000_000))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
+    use crate::solutions::Solver;
 
     #[test]
     fn d11() {
@@ -214,6 +225,9 @@ mod tests {
 #...#.....
 ";
 
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 374);
+        let input = Solution::parse_input(i).unwrap();
+        assert_eq!(Solution::distances(&input, 10), 1030);
+        assert_eq!(Solution::distances(&input, 100), 8410);
     }
 }
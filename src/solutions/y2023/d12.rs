@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
+use crate::solutions::progress::Progress;
 use crate::solutions::Solver;
 
 use crate::solutions::AocError;
@@ -66,14 +67,15 @@ struct Input {
 
 impl Solution {
     fn printElems(es: &[Elem]) {
-        for e in es {
-            match e {
-                Elem::Damaged => print!("#"),
-                Elem::Op => print!("."),
-                Elem::Unknown => print!("?"),
-            }
-        }
-        println!()
+        let row: String = es
+            .iter()
+            .map(|e| match e {
+                Elem::Damaged => '#',
+                Elem::Op => '.',
+                Elem::Unknown => '?',
+            })
+            .collect();
+        log::trace!("{}", row)
     }
 
     fn solve_1_cached(cache: &mut HashMap<Input, u64>, mut input: Input) -> u64 {
@@ -157,7 +159,8 @@ pub struct Spring {
 
 impl Solver for Solution {
     type Input = Vec<Spring>;
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Ok(input
@@ -182,8 +185,9 @@ impl Solver for Solution {
             .collect_vec())
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut sum = 0;
+        let mut progress = Progress::new("records", Some(input.len() as u64));
 
         for i in input {
             let min_length = i.record.iter().sum::<i32>() + i.record.len() as i32 - 1;
@@ -197,14 +201,16 @@ impl Solver for Solution {
             let mut cache = HashMap::new();
 
             let res = Solution::solve_1_cached(&mut cache, input);
-            println!("{} {:?}", res, i.record);
+            log::trace!("{} {:?}", res, i.record);
             sum += res;
+            progress.inc(1);
         }
         Ok(sum as u64)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut sum = 0;
+        let mut progress = Progress::new("records", Some(input.len() as u64));
         for i in input {
             let mut newrecord = vec![];
             let mut newElems = vec![];
@@ -225,6 +231,7 @@ impl Solver for Solution {
             let res = Solution::solve_1_cached(&mut cache, input) as u64;
 
             sum += res;
+            progress.inc(1);
         }
         Ok(sum)
     }
@@ -232,9 +239,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
-    use super::{Solution, State};
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d12() {
@@ -246,22 +252,24 @@ mod tests {
 ?###???????? 3,2,1";
         let i2 = "?#?????###? 2,4
 ";
-
         let i3 = "?###? 4";
         let i4 = "?# 2";
-        let r = Solution::solve(i, false).unwrap();
-        dbg!(r);
+
+        example_test!(Solution, i, part1 = 21, part2 = 525152);
+        example_test!(Solution, i2, part1 = 4);
+        example_test!(Solution, i3, part1 = 2);
+        example_test!(Solution, i4, part1 = 1);
     }
 
-    //#[test]
-    fn testM() {
+    #[test]
+    fn resolved() {
         let i = "#.#.### 1,1,3
 .#...#....###. 1,1,3
 .#.###.#.###### 1,3,1,6
 ####.#...#... 4,1,1
 #....######..#####. 1,6,5
 .###.##....# 3,2,1";
-        let r = Solution::solve(i, true).unwrap();
-        dbg!(r);
+
+        example_test!(Solution, i, part1 = 6, part2 = 6);
     }
 }
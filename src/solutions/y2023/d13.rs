@@ -35,7 +35,8 @@ impl Map {
 
 impl Solver for Solution {
     type Input = Vec<Map>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut maps = vec![];
@@ -64,7 +65,7 @@ impl Solver for Solution {
         Ok(maps)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut s = 0;
         for m in input {
             let (lc, lr) = m.shape();
@@ -81,7 +82,7 @@ impl Solver for Solution {
                 }
             }
             for pc in possible_col {
-                println!("possible col: {}", pc);
+                log::trace!("possible col: {}", pc);
                 let mut found = true;
                 for i in 0..pc {
                     let other = pc * 2 + 1 - i;
@@ -111,7 +112,7 @@ impl Solver for Solution {
                 }
             }
             for pr in possible_row {
-                println!("possible row : {}", pr);
+                log::trace!("possible row : {}", pr);
 
                 let mut found = true;
                 for i in 0..pr {
@@ -136,7 +137,7 @@ impl Solver for Solution {
                 (Some(col), None) => col * 100,
                 (None, Some(row)) => row,
                 (Some(col), Some(row)) => {
-                    println!("{} {}", row, col);
+                    log::trace!("{} {}", row, col);
                     unreachable!();
                 }
                 (None, None) => {
@@ -144,14 +145,14 @@ impl Solver for Solution {
                 }
             };
 
-            println!("{}", res);
+            log::debug!("{}", res);
             s += res;
         }
 
         return Ok(s);
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut s = 0;
         for m in input {
             let (lc, lr) = m.shape();
@@ -171,7 +172,7 @@ impl Solver for Solution {
                 }
             }
             for (pc, c) in possible_col {
-                println!("possible col: {} with {} diff", pc, c);
+                log::trace!("possible col: {} with {} diff", pc, c);
                 let mut count = c;
 
                 for i in 0..pc {
@@ -200,7 +201,7 @@ impl Solver for Solution {
                 }
             }
             for (pr, c) in possible_row {
-                println!("possible row : {} with {} diff", pr, c);
+                log::trace!("possible row : {} with {} diff", pr, c);
 
                 let mut count = c;
                 for i in 0..pr {
@@ -223,7 +224,7 @@ impl Solver for Solution {
                 (Some(col), None) => col * 100,
                 (None, Some(row)) => row,
                 (Some(col), Some(row)) => {
-                    println!("{} {}", row, col);
+                    log::trace!("{} {}", row, col);
                     unreachable!();
                 }
                 (None, None) => {
@@ -231,7 +232,7 @@ impl Solver for Solution {
                 }
             };
 
-            println!("{}", res);
+            log::debug!("{}", res);
             s += res;
         }
 
@@ -241,9 +242,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d13() {
@@ -263,14 +263,6 @@ mod tests {
 ..##..###
 #....#..#";
 
-        let i2 = "...##....
-.#.##.#..
-.#....#..
-.##..##..
-...##....
-..####.##
-#..##..##";
-
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 405, part2 = 400);
     }
 }
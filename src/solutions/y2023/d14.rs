@@ -166,7 +166,8 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Map<Elem>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let r = input
@@ -176,13 +177,13 @@ impl Solver for Solution {
         Ok(Map(r))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let tilted = Solution::tilt_north(&input);
         let res = Solution::score(&tilted);
         Ok(res)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let cycles = 1000000000;
         let mut tilted = input.clone();
 
@@ -206,7 +207,7 @@ impl Solver for Solution {
 
         let modres = (cycles - prev_cycle) % (current - prev_cycle);
 
-        println!(
+        log::debug!(
             "{} {} {} {}",
             prev_cycle,
             current,
@@ -218,7 +219,7 @@ impl Solver for Solution {
             mapping.insert(*k, *v);
         }
 
-        println!("{:?}", mapping);
+        log::debug!("{:?}", mapping);
 
         let res = mapping.get(&(prev_cycle + modres)).unwrap();
         Ok(*res)
@@ -227,9 +228,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d14() {
@@ -244,6 +244,6 @@ O.#..O.#.#
 #....###..
 #OO..#....";
 
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 136, part2 = 64);
     }
 }
@@ -18,13 +18,14 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<String>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Ok(input.split(',').map(|s| s.into()).collect_vec())
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut sum = 0;
         for step in input {
             let mut s = 0;
@@ -38,7 +39,7 @@ impl Solver for Solution {
         Ok(sum)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut sum = 0;
 
         let mut boxes: HashMap<i32, Vec<(String, i32)>> = HashMap::new();
@@ -61,10 +62,10 @@ impl Solver for Solution {
                 }
             }
         }
-        println!("{:?}", boxes);
+        log::debug!("{:?}", boxes);
         for (b, map) in boxes {
             for (pos, (label, lens)) in map.iter().enumerate() {
-                println!("{}: {} {} {}", label, b, pos, lens);
+                log::trace!("{}: {} {} {}", label, b, pos, lens);
                 let score = (b + 1) * (pos as i32 + 1) * lens;
                 sum += score;
             }
@@ -76,15 +77,13 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d15() {
         let i = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
-        let res = Solution::solve(i, false);
-        dbg!(res);
+        example_test!(Solution, i, part1 = 1320, part2 = 145);
     }
 }
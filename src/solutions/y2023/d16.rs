@@ -102,7 +102,8 @@ impl Display for Elem {
 
 impl Solver for Solution {
     type Input = Map<Elem>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let res = input
@@ -112,12 +113,12 @@ impl Solver for Solution {
         Ok(Map(res))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let res = Solution::count_energ(&input, (0, -1), (0, 1));
         return Ok(res);
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let s = input.shape();
 
         let mut m = 0;
@@ -143,9 +144,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d16() {
@@ -160,6 +160,6 @@ mod tests {
 .|....-|.\\
 ..//.|....";
 
-        dbg!(Solution::solve(i, true));
+        example_test!(Solution, i, part1 = 46, part2 = 51);
     }
 }
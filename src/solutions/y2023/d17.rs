@@ -2,6 +2,7 @@ use std::collections::{HashMap, VecDeque};
 
 use itertools::Itertools;
 
+use crate::solutions::{cancel, metrics};
 use crate::solutions::Solver;
 
 use crate::solutions::common::Map;
@@ -40,7 +41,8 @@ impl Dir {
 
 impl Solver for Solution {
     type Input = Map<u32>;
-    type Output = u32;
+    type Output1 = u32;
+    type Output2 = u32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         Ok(Map(input
@@ -49,7 +51,7 @@ impl Solver for Solution {
             .collect_vec()))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut v = VecDeque::new();
         v.push_front(((0, 0), Dir::Right, 0));
 
@@ -58,7 +60,11 @@ impl Solver for Solution {
         let mut dist: HashMap<((i32, i32), Dir, u32), u32> = HashMap::new();
         dist.insert(((0, 0), Dir::Right, 0), 0);
         let target = (x as i32 - 1, y as i32 - 1);
+        let mut states = metrics::Counter::new("states", 4096);
         while let Some((p, cur_dir, samedircounter)) = v.pop_front() {
+            if states.inc() {
+                cancel::check()?;
+            }
             let score = *dist.get(&(p, cur_dir, samedircounter)).unwrap();
             let neigh = if samedircounter == 2 {
                 if cur_dir == Dir::Right || cur_dir == Dir::Left {
@@ -106,7 +112,7 @@ impl Solver for Solution {
         Ok(min_dist)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut v = VecDeque::new();
         v.push_front(((0, 0), Dir::Right, 0, 0));
 
@@ -118,7 +124,11 @@ impl Solver for Solution {
         dist.insert(((0, 0), Dir::Right, 0, 0), 0);
         let target = (x as i32 - 1, y as i32 - 1);
         //println!("{:?}", target);
+        let mut states = metrics::Counter::new("states", 4096);
         while let Some((p, cur_dir, samedircounter, wobble)) = v.pop_front() {
+            if states.inc() {
+                cancel::check()?;
+            }
             let score = *dist.get(&(p, cur_dir, samedircounter, wobble)).unwrap();
             let neigh = if samedircounter < 3 {
                 vec![cur_dir]
@@ -195,16 +205,15 @@ impl Solver for Solution {
                 }
             }
         }
-        println!("minPath: {:?}", minpath);
+        log::debug!("minPath: {:?}", minpath);
         Ok(min_dist)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d17() {
@@ -221,14 +230,13 @@ mod tests {
 1224686865563
 2546548887735
 4322674655533";
-
         let i2 = "111111111111
 999999999991
 999999999991
 999999999991
 999999999991";
 
-        dbg!(Solution::solve(i, false));
-        dbg!(Solution::solve(i2, false));
+        example_test!(Solution, i, part1 = 102, part2 = 94);
+        example_test!(Solution, i2, part2 = 71);
     }
 }
@@ -26,7 +26,8 @@ pub struct Move {
 
 impl Solver for Solution {
     type Input = Vec<Move>;
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let lls = input
@@ -53,7 +54,7 @@ impl Solver for Solution {
         Ok(lls)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut dimx = 0;
         let mut dimy = 0;
         let mut curx = 0;
@@ -73,7 +74,7 @@ impl Solver for Solution {
             mx = mx.min(curx);
             my = my.min(cury);
         }
-        println!("{} {} {} {}", mx, my, dimx, dimy);
+        log::debug!("{} {} {} {}", mx, my, dimx, dimy);
         let shiftedx = -mx;
         let shiftedy = -my;
 
@@ -104,12 +105,12 @@ impl Solver for Solution {
         surf = surf / 2;
         let outside = edges;
         let p = surf + outside / 2 + 1;
-        map.print_map();
-        println!("{} {} => {}", surf, outside, p);
+        log::trace!("map:\n{}", map);
+        log::debug!("{} {} => {}", surf, outside, p);
         Ok(p as i64)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut surf = 0;
         let mut pos = (0, 0);
         let mut edges = 0;
@@ -135,7 +136,7 @@ impl Solver for Solution {
                 }
             }
 
-            println!("{:?} {}", dir, length);
+            log::trace!("{:?} {}", dir, length);
 
             let p = match dir {
                 Dir::Right => (0, length),
@@ -151,17 +152,16 @@ impl Solver for Solution {
         surf = surf / 2;
         let outside = edges;
         let p = surf + outside / 2 + 1;
-        println!("{} {} => {}", surf, outside, p);
+        log::debug!("{} {} => {}", surf, outside, p);
         Ok(p)
     }
 }
 
 #[cfg(test)]
 mod tests {
-
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
+
     #[test]
     fn d18() {
         let i = "R 6 (#70c710)
@@ -185,7 +185,7 @@ L 2 (#000000)
 U 2 (#000000)
 ";
 
-        dbg!(Solution::solve(i, true));
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 62, part2 = 952408144115_i64);
+        example_test!(Solution, i2, part1 = 9);
     }
 }
@@ -5,6 +5,7 @@ use nom::bytes::complete::*;
 use nom::character::complete::*;
 use nom::combinator::all_consuming;
 use nom::combinator::map;
+use nom::combinator::map_res;
 use nom::multi::fold_many0;
 use nom::multi::fold_many1;
 use nom::multi::many1;
@@ -89,15 +90,14 @@ fn rule2(input: &str) -> IResult<&str, Rule> {
 }
 
 fn rule(input: &str) -> IResult<&str, Vec<Rule>> {
-    let cond1 = map(tuple((alpha1, take(1_usize), digit1)), |(a, sign, b)| {
-        let d = match sign {
-            ">" => Dir::MORE,
-            "<" => Dir::LESS,
-            _ => unimplemented!(),
-        };
-        let cond = Rule::Cond(Cond(a.to_owned(), d, b.parse().unwrap()));
-        cond
-    });
+    let cond1 = map(
+        tuple((alpha1, one_of("<>"), map_res(digit1, str::parse))),
+        |(a, sign, b): (&str, char, u64)| {
+            let d = if sign == '>' { Dir::MORE } else { Dir::LESS };
+            let cond = Rule::Cond(Cond(a.to_owned(), d, b));
+            cond
+        },
+    );
 
     let cond2 = alt((
         map(
@@ -118,15 +118,16 @@ fn rule(input: &str) -> IResult<&str, Vec<Rule>> {
     Ok((rem, res))
 }
 
-fn workflow(input: &str) -> Workflow {
+fn workflow(input: &str) -> IResult<&str, Workflow> {
     let delim_rule = delimited(char('{'), rule, char('}'));
-    let mut parser = pair(alpha1, delim_rule);
+    let mut parser = map(pair(alpha1, delim_rule), |(name, res): (&str, _)| {
+        Workflow {
+            name: name.to_owned(),
+            cond: res,
+        }
+    });
 
-    let (_, (name, res)) = parser(input).unwrap();
-    Workflow {
-        name: name.to_owned(),
-        cond: res,
-    }
+    parser(input)
 }
 
 fn recurse_count(
@@ -135,7 +136,7 @@ fn recurse_count(
     validPaths: &mut Vec<Vec<Range>>,
     mut currentPath: Vec<Range>,
 ) {
-    println!("{}", w);
+    log::trace!("{}", w);
     let w = ws.get(w).unwrap();
     for index in (0..w.cond.len()).step_by(2) {
         let c1 = &w.cond[index];
@@ -181,26 +182,26 @@ fn recurse_count(
 pub struct Part(HashMap<String, u64>);
 
 fn partvalue(input: &str) -> IResult<&str, (&str, u64)> {
-    let mut value = separated_pair(alpha1, tag("="), digit1);
-    let (rem, (k, v)) = value(input)?;
-    Ok((rem, (k, v.parse().unwrap())))
+    let mut value = separated_pair(alpha1, tag("="), map_res(digit1, str::parse));
+    value(input)
 }
-fn part(input: &str) -> Part {
+fn part(input: &str) -> IResult<&str, Part> {
     let values = separated_list1(char(','), partvalue);
     let mut parser = delimited(char('{'), values, char('}'));
 
-    let (_, vs) = parser(input).unwrap();
+    let (rem, vs) = parser(input)?;
     let mut hm = HashMap::new();
 
     for (k, v) in vs {
         hm.insert(k.to_owned(), v);
     }
-    Part(hm)
+    Ok((rem, Part(hm)))
 }
 
 impl Solver for Solution {
     type Input = (HashMap<String, Workflow>, Vec<Part>);
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut lines = input.lines();
@@ -211,19 +212,19 @@ impl Solver for Solution {
             if l == "" {
                 break;
             }
-            let w = workflow(l);
+            let (_, w) = all_consuming(workflow)(l).map_err(|e| AocError::from_nom(input, e))?;
             worflow.insert(w.name.clone(), w);
         }
 
         while let Some(l) = lines.next() {
-            let p = part(l);
+            let (_, p) = all_consuming(part)(l).map_err(|e| AocError::from_nom(input, e))?;
             parts.push(p);
         }
 
         Ok((worflow, parts))
     }
 
-    fn solve_part1((ws, ps): Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1((ws, ps): Self::Input) -> Result<Self::Output1, AocError> {
         let mut sp = 0;
         for p in ps {
             let mut w = ws.get("in").unwrap();
@@ -266,13 +267,13 @@ impl Solver for Solution {
         Ok(sp as u64)
     }
 
-    fn solve_part2((ws, _): Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2((ws, _): Self::Input) -> Result<Self::Output2, AocError> {
         let mut validPaths = vec![];
         recurse_count("in", &ws, &mut validPaths, vec![]);
 
         let mut sum = 0;
         for vp in validPaths {
-            println!("{:?}", vp);
+            log::trace!("{:?}", vp);
             let mut x = Range("x".into(), 1, 4001);
             let mut m = Range("m".into(), 1, 4001);
             let mut a = Range("a".to_owned(), 1, 4001);
@@ -287,7 +288,7 @@ impl Solver for Solution {
                     _ => {}
                 }
             }
-            println!("x: {:?}, m: {:?}, a: {:?}, s: {:?}", x, m, a, s);
+            log::trace!("x: {:?}, m: {:?}, a: {:?}, s: {:?}", x, m, a, s);
             /*println!(
                 "x: {:?}, m: {:?}, a: {:?}, s: {:?}",
                 x.len(),
@@ -297,7 +298,7 @@ impl Solver for Solution {
             );*/
 
             let res = x.len() * a.len() * m.len() * s.len();
-            println!("{}", res);
+            log::trace!("{}", res);
             sum += res;
         }
         Ok(sum)
@@ -306,9 +307,10 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
+    use crate::solutions::{AocError, Solver};
+
     #[test]
     fn d19() {
         let i = "px{a<2006:qkq,m>2090:A,rfg}
@@ -330,6 +332,17 @@ hdj{m>838:A,pv}
 {x=2127,m=1623,a=2188,s=1013}
 ";
 
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 19114, part2 = 167409079868000_i64);
+    }
+
+    #[test]
+    fn huge_rating() {
+        let e = Solution::parse_input("in{A}\n\n{x=99999999999999999999}\n")
+            .err()
+            .unwrap();
+        match e {
+            AocError::Parse { line, column, .. } => assert_eq!((line, column), (3, 4)),
+            e => panic!("unexpected {:?}", e),
+        }
     }
 }
@@ -1,5 +1,3 @@
-
-
 use itertools::Itertools;
 
 use crate::solutions::Solver;
@@ -23,7 +21,8 @@ pub struct Game {
 
 impl Solver for Solution {
     type Input = Vec<Game>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let r = input
@@ -59,7 +58,7 @@ impl Solver for Solution {
         Ok(r)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut s = 0;
         for g in input {
             let red = 12;
@@ -80,7 +79,7 @@ impl Solver for Solution {
         Ok(s)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut s = 0;
         for g in input {
             let mut red = 0;
@@ -106,4 +105,19 @@ impl Solver for Solution {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::Solution;
+    use crate::solutions::test_support::example_test;
+
+    #[test]
+    fn d2() {
+        let i = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+        example_test!(Solution, i, part1 = 8, part2 = 2286);
+    }
+}
@@ -7,6 +7,7 @@ use std::time::Instant;
 
 use itertools::Itertools;
 
+use crate::solutions::{cancel, metrics};
 use crate::solutions::Solver;
 
 use crate::solutions::AocError;
@@ -31,16 +32,22 @@ pub struct Module {
 
 impl Solver for Solution {
     type Input = HashMap<String, Module>;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut hm = HashMap::new();
         let mut input_count = HashMap::new();
         for l in input.lines() {
-            let (n, output) = l.split_once(" -> ").unwrap();
+            let (n, output) = l
+                .split_once(" -> ")
+                .ok_or_else(|| AocError::parse(input, l, "expected `NAME -> OUTPUTS`"))?;
 
             let mut chars = n.chars().peekable();
-            let t = match chars.peek().unwrap() {
+            let t = match chars
+                .peek()
+                .ok_or_else(|| AocError::parse(input, l, "empty name"))?
+            {
                 '%' => {
                     _ = chars.next();
                     MType::FlipFlop(false)
@@ -82,10 +89,11 @@ impl Solver for Solution {
         Ok(hm)
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(mut input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut high = 0;
         let mut low = 0;
 
+        metrics::count("presses", 1000);
         for _ in 0..1000 {
             let mut h: Vec<(String, String, bool)> =
                 vec![("button".into(), "broadcaster".into(), false)];
@@ -127,11 +135,11 @@ impl Solver for Solution {
                 next_c = vec![];
             }
         }
-        println!("{} {}", high, low);
+        log::debug!("{} {}", high, low);
         Ok(high * low)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut thc: HashMap<String, Option<usize>> = HashMap::new();
 
         let (_, module) = input
@@ -143,11 +151,15 @@ impl Solver for Solution {
                 thc.insert(k.clone(), None);
             }
         }
-        println!("{:?}", module);
+        log::debug!("{:?}", module);
         let mut notdone = true;
         let mut button = 0;
+        let mut presses = metrics::Counter::new("presses", 256);
         while notdone {
             button += 1;
+            if presses.inc() {
+                cancel::check()?;
+            }
             let mut h: Vec<(&str, &str, bool)> = vec![("button", "broadcaster", false)];
             let mut next_c: Vec<(&str, &str, bool)> = vec![];
             while h.len() > 0 {
@@ -168,7 +180,7 @@ impl Solver for Solution {
                                 if b.name == module.name && s && button > 1 {
                                     if let Some(v) = thc.get_mut(source) {
                                         if v.is_none() {
-                                            println!("{} => {}", source, button);
+                                            log::trace!("{} => {}", source, button);
                                             *v = Some(button);
                                         }
                                     }
@@ -185,7 +197,7 @@ impl Solver for Solution {
                         }
                     } else {
                         if dest == "rx" && !s {
-                            println!("found {}", button);
+                            log::debug!("found {}", button);
                             return Ok(button);
                         }
                     }
@@ -195,7 +207,7 @@ impl Solver for Solution {
             }
             notdone = !thc.values().all(|v| v.is_some());
         }
-        println!("{:?}", thc);
+        log::debug!("{:?}", thc);
         let mut a = 1;
         for v in thc.into_values().filter_map(|a| a) {
             a = num::integer::lcm(a, v);
@@ -206,9 +218,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d20() {
@@ -217,13 +228,14 @@ mod tests {
 %b -> c
 %c -> inv
 &inv -> a";
-
         let i2 = "broadcaster -> a
 %a -> inv, con
 &inv -> b
 %b -> con
 &con -> output";
-        dbg!(Solution::solve(i, false));
-        dbg!(Solution::solve(i2, false));
+
+        // The examples have no `rx` module, so part 2 cannot run on them.
+        example_test!(Solution, i, part1 = 32000000);
+        example_test!(Solution, i2, part1 = 11687500);
     }
 }
@@ -9,11 +9,44 @@ use crate::solutions::AocError;
 
 pub enum Solution {}
 
-impl Solution {}
+impl Solution {
+    // Plots reached in exactly `steps` steps.
+    fn reachable(start: (i64, i64), map: &Map<char>, steps: usize) -> i64 {
+        let mut h = HashSet::new();
+        h.insert(start);
+        let mut next_c = HashSet::new();
+        let neigh = vec![(0, 1), (0, -1), (1, 0), (-1, 0)];
+        let (x, y) = map.shape();
+        log::debug!("{} {}", x, y);
+        for _ in 0..steps {
+            for p in h {
+                //println!("{:?}", p);
+                for n in neigh.iter() {
+                    let newp = (p.0 + n.0, p.1 + n.1);
+                    if newp.0 < 0 || newp.1 < 0 || newp.0 >= y as i64 || newp.1 >= x as i64 {
+                        continue;
+                    }
+                    let elem = map.0[newp.0 as usize][newp.1 as usize];
+                    match elem {
+                        '.' => {
+                            next_c.insert(newp.clone());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            h = next_c;
+            next_c = HashSet::new();
+        }
+
+        h.len() as i64
+    }
+}
 
 impl Solver for Solution {
     type Input = ((i64, i64), Map<char>);
-    type Output = i64;
+    type Output1 = i64;
+    type Output2 = i64;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut start = (0, 0);
@@ -37,41 +70,14 @@ impl Solver for Solution {
         Ok((start, Map(res)))
     }
 
-    fn solve_part1((start, map): Self::Input) -> Result<Self::Output, AocError> {
-        let mut h = HashSet::new();
-        h.insert(start);
-        let mut next_c = HashSet::new();
-        let neigh = vec![(0, 1), (0, -1), (1, 0), (-1, 0)];
-        let (x, y) = map.shape();
-        println!("{} {}", x, y);
-        for _ in 0..64 {
-            for p in h {
-                //println!("{:?}", p);
-                for n in neigh.iter() {
-                    let newp = (p.0 + n.0, p.1 + n.1);
-                    if newp.0 < 0 || newp.1 < 0 || newp.0 >= y as i64 || newp.1 >= x as i64 {
-                        continue;
-                    }
-                    let elem = map.0[newp.0 as usize][newp.1 as usize];
-                    match elem {
-                        '.' => {
-                            next_c.insert(newp.clone());
-                        }
-                        _ => {}
-                    }
-                }
-            }
-            h = next_c;
-            next_c = HashSet::new();
-        }
-
-        Ok(h.len() as i64)
+    fn solve_part1((start, map): Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(Solution::reachable(start, &map, 64))
     }
 
-    fn solve_part2((start, map): Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2((start, map): Self::Input) -> Result<Self::Output2, AocError> {
         let (x, y) = map.shape();
 
-        println!("{} {}", x, y);
+        log::debug!("{} {}", x, y);
 
         let cycle = 26501365;
         let s1 = cycle % x;
@@ -82,8 +88,8 @@ impl Solver for Solution {
         let c2 = cycle_count(&map, start, s2);
         let c3 = cycle_count(&map, start, s3);
 
-        println!("{} {} {}", s1, s2, s3);
-        println!("{} {} {}", c1, c2, c3);
+        log::debug!("{} {} {}", s1, s2, s3);
+        log::debug!("{} {} {}", c1, c2, c3);
         let s1f = s1 as f64;
         let s2f = s2 as f64;
         let s3f = s3 as f64;
@@ -100,13 +106,13 @@ impl Solver for Solution {
         let scalars = invn * ys;
 
         let res2 = scalars[2] * (cf * cf) + scalars[1] * cf + scalars[0];
-        println!("{}", res2);
+        log::debug!("{}", res2);
         let c = c1;
         let b = (4 * c2 - 3 * c1 - c3) / 2;
         let a = (c3 + c1 - 2 * c2) / 2;
         let cx = (cycle / x) as i64;
         let res3 = a * cx * cx + b * cx + c;
-        println!("{}", res3);
+        log::debug!("{}", res3);
 
         Ok(res2.round() as i64)
     }
@@ -152,9 +158,8 @@ fn next_cycle(map: &Map<char>, input: &HashSet<(i64, i64)>) -> HashSet<(i64, i64
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::Solver;
 
     #[test]
     fn d21() {
@@ -171,6 +176,8 @@ mod tests {
 ...........
 ";
 
-        dbg!(Solution::solve(i, false));
+        // Part 2 relies on properties of the real input.
+        let (start, map) = Solution::parse_input(i).unwrap();
+        assert_eq!(Solution::reachable(start, &map, 6), 16);
     }
 }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
 
-use crate::solutions::Solver;
+use crate::solutions::SharedSolver;
 
 use crate::solutions::common::Map;
 use crate::solutions::AocError;
@@ -14,31 +14,45 @@ impl Solution {}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pos(i64, i64, i64);
 
-impl Solver for Solution {
-    type Input = Vec<(Pos, Pos)>;
-    type Output = i32;
+// Bricks after settling, with the bricks each one supports and is supported by.
+pub struct Settled {
+    bricks: usize,
+    tree: HashMap<usize, Vec<usize>>,
+    invtree: HashMap<usize, Vec<usize>>,
+}
 
-    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
-        Ok(input
+impl SharedSolver for Solution {
+    type Input = Vec<(Pos, Pos)>;
+    type Shared = Settled;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let pos = |s: &str| -> Result<Pos, AocError> {
+            let coords: Vec<i64> = s
+                .split(',')
+                .map(|o| {
+                    o.parse()
+                        .map_err(|e| AocError::parse(input, o, format!("invalid number: {}", e)))
+                })
+                .try_collect()?;
+            match coords[..] {
+                [x, y, z] => Ok(Pos(x, y, z)),
+                _ => Err(AocError::parse(input, s, "expected 3 coordinates")),
+            }
+        };
+        input
             .lines()
             .map(|l| {
-                let (a, b) = l.split_once('~').unwrap();
-                let p1: (i64, i64, i64) = a
-                    .split(',')
-                    .map(|o| o.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap();
-                let p2: (i64, i64, i64) = b
-                    .split(',')
-                    .map(|o| o.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap();
-                (Pos(p1.0, p1.1, p1.2), Pos(p2.0, p2.1, p2.2))
+                let (a, b) = l
+                    .split_once('~')
+                    .ok_or_else(|| AocError::parse(input, l, "expected `START~END`"))?;
+                Ok((pos(a)?, pos(b)?))
             })
-            .collect_vec())
+            .collect()
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, AocError> {
+    fn analyze(input: &Self::Input) -> Result<Self::Shared, AocError> {
         let max = input.iter().fold((0, 0, 0), |a, (b, c)| {
             let x = (a.0.max(b.0).max(c.0));
             let y = (a.1.max(b.1).max(c.1));
@@ -47,8 +61,9 @@ impl Solver for Solution {
         });
         let maxA = (max.0 as usize + 1, max.1 as usize + 1, max.2 as usize + 1);
 
-        println!("{:?}", max);
+        log::debug!("{:?}", max);
 
+        let mut input = input.clone();
         input.sort_by_key(|a| a.0 .2.min(a.1 .2));
         let mut tree: HashMap<usize, Vec<usize>> = HashMap::new();
         let newpos = update(&input, maxA);
@@ -67,11 +82,25 @@ impl Solver for Solution {
                 invtree.entry(*a).or_insert(vec![]).push(*k);
             }
         }
-        println!("{:?}", tree);
-        println!("{:?}", invtree);
+
+        Ok(Settled {
+            bricks: input.len(),
+            tree,
+            invtree,
+        })
+    }
+
+    fn part1(_: &Self::Input, settled: &Self::Shared) -> Result<Self::Output1, AocError> {
+        let Settled {
+            bricks,
+            tree,
+            invtree,
+        } = settled;
+        log::debug!("{:?}", tree);
+        log::debug!("{:?}", invtree);
 
         let mut count = 0;
-        for id in 0..input.len() {
+        for id in 0..*bricks {
             if let Some(supports) = tree.get(&id) {
                 let mut canremove = true;
                 for a in supports {
@@ -94,39 +123,10 @@ impl Solver for Solution {
         Ok(count)
     }
 
-    fn solve_part2(mut input: Self::Input) -> Result<Self::Output, AocError> {
-        let max = input.iter().fold((0, 0, 0), |a, (b, c)| {
-            let x = (a.0.max(b.0).max(c.0));
-            let y = (a.1.max(b.1).max(c.1));
-            let z = (a.2.max(b.2).max(c.2));
-            (x, y, z)
-        });
-        let maxA = (max.0 as usize + 1, max.1 as usize + 1, max.2 as usize + 1);
-
-        println!("{:?}", max);
-
-        input.sort_by_key(|a| a.0 .2.min(a.1 .2));
-        let mut tree: HashMap<usize, Vec<usize>> = HashMap::new();
-        let newpos = update(&input, maxA);
-        for (idp, p) in newpos.iter().enumerate() {
-            for (idq, q) in newpos.iter().enumerate() {
-                if support(p, q) {
-                    tree.entry(idp).or_insert(vec![]).push(idq);
-                }
-            }
-        }
-
-        let mut invtree = HashMap::new();
-
-        for (k, v) in tree.iter() {
-            for a in v {
-                invtree.entry(*a).or_insert(vec![]).push(*k);
-            }
-        }
-
+    fn part2(_: &Self::Input, settled: &Self::Shared) -> Result<Self::Output2, AocError> {
         let mut count = 0;
-        for id in 0..input.len() {
-            count += dfs(&tree, &invtree, id);
+        for id in 0..settled.bricks {
+            count += dfs(&settled.tree, &settled.invtree, id);
         }
 
         Ok(count as i32)
@@ -243,9 +243,9 @@ fn update(input: &Vec<(Pos, Pos)>, (x, y, _): (usize, usize, usize)) -> Vec<(Pos
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
+    use crate::solutions::SharedSolver;
 
     #[test]
     fn d22() {
@@ -258,7 +258,15 @@ mod tests {
 1,1,8~1,1,9
 ";
 
-        dbg!(Solution::solve(i, true));
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 5, part2 = 7);
+    }
+
+    #[test]
+    fn bad_brick() {
+        let e = Solution::parse("1,0,1~1,2,1\n0,0,2~2,x,2\n").err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 9: invalid number: invalid digit found in string"
+        );
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
-use crate::solutions::Solver;
+use crate::solutions::progress::Progress;
+use crate::solutions::SharedSolver;
 use itertools::Itertools;
 
 use crate::solutions::common::Map;
@@ -8,85 +9,21 @@ use crate::solutions::AocError;
 
 pub enum Solution {}
 
-impl Solution {}
+type Pos = (i32, i32);
 
-impl Solver for Solution {
-    type Input = Map<char>;
-    type Output = i32;
-
-    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
-        Ok(Map(input
-            .lines()
-            .map(|l| l.chars().collect_vec())
-            .collect_vec()))
-    }
-
-    fn solve_part1(map: Self::Input) -> Result<Self::Output, AocError> {
-        let (x, y) = map.shape();
-
-        let mut start = (0i32, 0i32);
-        let mut end = (0i32, 0i32);
-        for j in 0..y {
-            if map.0[0][j] == '.' {
-                start = (0, j as i32);
-            }
-            if map.0[y - 1][j] == '.' {
-                end = (y as i32 - 1, j as i32);
-            }
-        }
-
-        let mut queue = VecDeque::new();
-        queue.push_back((start, HashSet::new()));
-
-        let mut res = vec![];
-        while let Some((q, mut path)) = queue.pop_front() {
-            if !path.insert(q) {
-                continue;
-            }
-            if q == end {
-                res.push(path.len() - 1);
-            }
-            let m = map.0[q.0 as usize][q.1 as usize];
-            let neigh = match m {
-                '>' => vec![(0, 1)],
-                '<' => vec![(0, -1)],
-                '^' => vec![(-1, 0)],
-                'v' => vec![(1, 0)],
-                '.' => vec![(0, 1), (0, -1), (1, 0), (-1, 0)],
-                _ => vec![],
-            };
-            for n in neigh {
-                let newpos = (q.0 + n.0, q.1 + n.1);
-                if newpos.0 < 0 || newpos.1 < 0 || newpos.0 as usize >= x || newpos.1 as usize >= y
-                {
-                    continue;
-                }
-                let m = map.0[newpos.0 as usize][newpos.1 as usize];
-                if m != '#' {
-                    queue.push_back((newpos, path.clone()));
-                }
-            }
-        }
-        println!("{:?}", res);
-
-        let r = *res.iter().max().unwrap();
-        Ok(r as i32)
-    }
+// The trails compressed to a graph of their intersections, ignoring the
+// slopes, with the length of the trail between each pair.
+struct Graph {
+    intersections: HashMap<Pos, HashSet<Pos>>,
+    dists: HashMap<(Pos, Pos), i32>,
+}
 
-    fn solve_part2(map: Self::Input) -> Result<Self::Output, AocError> {
+impl Solution {
+    fn compress(trails: &Trails) -> Graph {
+        let Trails { map, start, end } = trails;
+        let (start, end) = (*start, *end);
         let (x, y) = map.shape();
 
-        let mut start = (0i32, 0i32);
-        let mut end = (0i32, 0i32);
-        for j in 0..y {
-            if map.0[0][j] == '.' {
-                start = (0, j as i32);
-            }
-            if map.0[y - 1][j] == '.' {
-                end = (y as i32 - 1, j as i32);
-            }
-        }
-
         let mut edges = Vec::new();
 
         let mut queue = VecDeque::new();
@@ -118,7 +55,7 @@ impl Solver for Solution {
                 edges.push((end, prev, d + 1));
                 edges.push((prev, end, d + 1));
             }
-            println!("{:?} {:?} {:?}", prev, p, sum_path);
+            log::trace!("{:?} {:?} {:?}", prev, p, sum_path);
             if paths.len() > 2 {
                 for n in paths.iter() {
                     queue.push_back((p, n.clone(), 0));
@@ -142,13 +79,109 @@ impl Solver for Solution {
             dists.insert((v, k), d);
         }
 
+        Graph {
+            intersections,
+            dists,
+        }
+    }
+}
+
+// The map, with where the trails start and end.
+pub struct Trails {
+    map: Map<char>,
+    start: Pos,
+    end: Pos,
+}
+
+impl SharedSolver for Solution {
+    type Input = Trails;
+    type Shared = ();
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let map = Map(input.lines().map(|l| l.chars().collect_vec()).collect_vec());
+        let (_, y) = map.shape();
+
+        let mut start = (0i32, 0i32);
+        let mut end = (0i32, 0i32);
+        for j in 0..y {
+            if map.0[0][j] == '.' {
+                start = (0, j as i32);
+            }
+            if map.0[y - 1][j] == '.' {
+                end = (y as i32 - 1, j as i32);
+            }
+        }
+        Ok(Trails { map, start, end })
+    }
+
+    fn analyze(_: &Self::Input) -> Result<Self::Shared, AocError> {
+        Ok(())
+    }
+
+    fn part1(trails: &Self::Input, _: &()) -> Result<Self::Output1, AocError> {
+        let Trails { map, start, end } = trails;
+        let (start, end) = (*start, *end);
+        let (x, y) = map.shape();
+
+        let mut queue = VecDeque::new();
+        queue.push_back((start, HashSet::new()));
+
+        let mut res = vec![];
+        while let Some((q, mut path)) = queue.pop_front() {
+            if !path.insert(q) {
+                continue;
+            }
+            if q == end {
+                res.push(path.len() - 1);
+            }
+            let m = map.0[q.0 as usize][q.1 as usize];
+            let neigh = match m {
+                '>' => vec![(0, 1)],
+                '<' => vec![(0, -1)],
+                '^' => vec![(-1, 0)],
+                'v' => vec![(1, 0)],
+                '.' => vec![(0, 1), (0, -1), (1, 0), (-1, 0)],
+                _ => vec![],
+            };
+            for n in neigh {
+                let newpos = (q.0 + n.0, q.1 + n.1);
+                if newpos.0 < 0 || newpos.1 < 0 || newpos.0 as usize >= x || newpos.1 as usize >= y
+                {
+                    continue;
+                }
+                let m = map.0[newpos.0 as usize][newpos.1 as usize];
+                if m != '#' {
+                    queue.push_back((newpos, path.clone()));
+                }
+            }
+        }
+        log::debug!("{:?}", res);
+
+        let r = *res.iter().max().unwrap();
+        Ok(r as i32)
+    }
+
+    // Only part 2 ignores the slopes, so only it pays for compressing the trails.
+    fn part2(trails: &Self::Input, _: &()) -> Result<Self::Output2, AocError> {
+        let Graph {
+            intersections,
+            dists,
+        } = Solution::compress(trails);
+        let (start, end) = (&trails.start, &trails.end);
         let mut queue = VecDeque::new();
         //let mut cache = HashMap::new();
-        queue.push_back((start, BTreeSet::new(), 0));
+        queue.push_back((*start, BTreeSet::new(), 0));
         let mut maxpath = 0;
+        let mut progress = Progress::new("paths", None);
         while let Some((q, mut path, dist)) = queue.pop_front() {
-            if q == end {
-                maxpath = maxpath.max(dist);
+            progress.inc(1);
+            if q == *end {
+                if dist > maxpath {
+                    maxpath = dist;
+                    progress.best(maxpath);
+                }
                 //println!("{:?}", dist);
                 continue;
             }
@@ -181,9 +214,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d23() {
@@ -212,7 +244,6 @@ mod tests {
 #####################.#
 ";
 
-        dbg!(Solution::solve(i, true));
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 94, part2 = 154);
     }
 }
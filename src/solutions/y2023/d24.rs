@@ -7,38 +7,10 @@ use crate::solutions::AocError;
 
 pub enum Solution {}
 
-impl Solution {}
-struct Rect {
-    top: i64,
-    bottom: i64,
-    left: i64,
-    right: i64,
-}
-
-impl Solver for Solution {
-    type Input = Vec<(Vector3<i64>, Vector3<i64>)>;
-    type Output = i64;
-
-    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
-        let r = input
-            .lines()
-            .map(|l: &str| {
-                let (p, v) = l.split_once('@').unwrap();
-                let p = Vector3::from_iterator(p.split(", ").map(|r| r.trim().parse().unwrap()));
-                let v = Vector3::from_iterator(v.split(", ").map(|r| r.trim().parse().unwrap()));
-                (p, v)
-            })
-            .collect_vec();
-        Ok(r)
-    }
-
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
-        let min = 200000000000000f64;
-        let max = 400000000000000f64;
-
-        //let min = 7f64;
-        //let max = 27f64;
-
+impl Solution {
+    // Crossings of the paths ahead of the hailstones, ignoring z, within the
+    // test area.
+    fn crossings(input: &[(Vector3<i64>, Vector3<i64>)], min: f64, max: f64) -> i64 {
         let mut curves = vec![];
         for (p, v) in input.iter() {
             let a = v.y as f64 / v.x as f64;
@@ -65,10 +37,56 @@ impl Solver for Solution {
             }
         }
 
-        Ok(count)
+        count
+    }
+}
+struct Rect {
+    top: i64,
+    bottom: i64,
+    left: i64,
+    right: i64,
+}
+
+impl Solver for Solution {
+    type Input = Vec<(Vector3<i64>, Vector3<i64>)>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse_input(input: &str) -> Result<Self::Input, AocError> {
+        let vector = |s: &str| -> Result<Vector3<i64>, AocError> {
+            let coords: Vec<i64> = s
+                .split(',')
+                .map(|r| {
+                    let r = r.trim();
+                    r.parse()
+                        .map_err(|e| AocError::parse(input, r, format!("invalid number: {}", e)))
+                })
+                .try_collect()?;
+            if coords.len() != 3 {
+                return Err(AocError::parse(input, s, "expected 3 coordinates"));
+            }
+            Ok(Vector3::from_vec(coords))
+        };
+        input
+            .lines()
+            .map(|l: &str| {
+                let (p, v) = l
+                    .split_once('@')
+                    .ok_or_else(|| AocError::parse(input, l, "expected `POSITION @ VELOCITY`"))?;
+                Ok((vector(p)?, vector(v)?))
+            })
+            .collect()
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
+        Ok(Solution::crossings(
+            &input,
+            200000000000000f64,
+            400000000000000f64,
+        ))
+    }
+
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         // p + t *v = p1 + t*v1;
         // p+ t2 *v = p2 + t2*v2;
         // p + t3 *v = p3 + t3*v3;
@@ -99,10 +117,10 @@ impl Solver for Solution {
             opt.assert(&(&pz + &t * &vz)._eq(&(&pz1 + &t * &vz1)));
         }
 
-        println!("Run");
+        log::debug!("Run");
 
         let a = opt.check();
-        println!("{:?}", a);
+        log::debug!("{:?}", a);
         let r = opt.get_model().unwrap();
         let px = r.eval(&px, true).unwrap().as_i64().unwrap();
         let py = r.eval(&py, true).unwrap().as_i64().unwrap();
@@ -111,7 +129,7 @@ impl Solver for Solution {
         let vy = r.eval(&vy, true).unwrap().as_i64().unwrap();
         let vz = r.eval(&vz, true).unwrap().as_i64().unwrap();
 
-        println!("{} {} {} {} {} {}", px, py, pz, vx, vy, vz);
+        log::debug!("{} {} {} {} {} {}", px, py, pz, vx, vy, vz);
         let res = px + py + pz;
         Ok(res)
     }
@@ -119,9 +137,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::Solver;
 
     #[test]
     fn d24() {
@@ -131,6 +148,7 @@ mod tests {
 12, 31, 28 @ -1, -2, -1
 20, 19, 15 @  1, -5, -3";
 
-        dbg!(Solution::solve(i, true));
+        let input = Solution::parse_input(i).unwrap();
+        assert_eq!(Solution::crossings(&input, 7., 27.), 2);
     }
 }
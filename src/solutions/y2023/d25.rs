@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
+use crate::solutions::{cancel, metrics};
 use crate::solutions::Solver;
 
 use crate::solutions::AocError;
@@ -59,7 +60,8 @@ fn bfs(
 
 impl Solver for Solution {
     type Input = Vec<(String, String)>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut hm = vec![];
@@ -73,7 +75,7 @@ impl Solver for Solution {
         Ok(hm)
     }
 
-    fn solve_part1(edges: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(edges: Self::Input) -> Result<Self::Output1, AocError> {
         let mut vertex = HashSet::new();
         for e in edges.iter() {
             vertex.insert(e.0.clone());
@@ -84,22 +86,32 @@ impl Solver for Solution {
         let mut g1: usize;
         let mut g2: usize;
         let mut minsofar = usize::MAX;
+        // Karger's algorithm is randomized: retry until the 3-edge cut is found
+        let mut attempts = metrics::Counter::new("attempts", 8);
         loop {
+            if attempts.inc() {
+                cancel::check()?;
+            }
             (cut, g1, g2) = mincut(&edges, &vertex);
             if cut.len() == 3 {
                 break;
             } else if minsofar > cut.len() {
                 minsofar = cut.len();
-                println!("{:?}", minsofar);
+                log::trace!("{:?}", minsofar);
             }
         }
 
-        println!("{:?} {} {}", cut, g1, g2);
+        log::debug!("{:?} {} {}", cut, g1, g2);
         Ok((g1 * g2) as i32)
     }
 
-    fn solve_part2(_input: Self::Input) -> Result<Self::Output, AocError> {
-        unimplemented!()
+    // The last day has no second puzzle.
+    fn solve_part2(_input: Self::Input) -> Result<Self::Output2, AocError> {
+        Err(AocError::PartNotImplemented {
+            year: 2023,
+            day: 25,
+            part: 2,
+        })
     }
 }
 
@@ -141,10 +153,9 @@ fn mincut(
 
 #[cfg(test)]
 mod tests {
-
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
+
     #[test]
     fn d25() {
         let i = "jqt: rhn xhk nvd
@@ -161,6 +172,6 @@ lsr: lhk
 rzs: qnr cmg lsr rsh
 frs: qnr lhk lsr";
 
-        dbg!(Solution::solve(i, true));
+        example_test!(Solution, i, part1 = 54);
     }
 }
@@ -22,7 +22,8 @@ pub struct Token {
 
 impl Solver for Solution {
     type Input = Vec<Token>;
-    type Output = u32;
+    type Output1 = u32;
+    type Output2 = u32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut y = 0;
@@ -77,7 +78,7 @@ impl Solver for Solution {
         Ok(tokens)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut sum = 0;
 
         let mut values = vec![];
@@ -108,7 +109,7 @@ impl Solver for Solution {
         Ok(sum)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut sum = 0;
 
         let mut values = vec![];
@@ -150,10 +151,10 @@ impl Solver for Solution {
 #[cfg(test)]
 mod tests {
     use super::Solution;
-    use crate::solutions::Solver;
+    use crate::solutions::test_support::example_test;
 
     #[test]
-    fn a() {
+    fn d3() {
         let i = "467..114..
 ...*......
 ..35..633.
@@ -165,7 +166,6 @@ mod tests {
 ...$.*....
 .664.598..";
 
-        let a = Solution::solve(i, true).unwrap();
-        dbg!(a);
+        example_test!(Solution, i, part1 = 4361, part2 = 467835);
     }
 }
@@ -20,7 +20,8 @@ pub struct Card {
 
 impl Solver for Solution {
     type Input = Vec<Card>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let r = input
@@ -49,7 +50,7 @@ impl Solver for Solution {
         Ok(r)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut sum = 0;
         for c in input {
             let s = c.part1.intersection(&c.part2).collect_vec();
@@ -60,7 +61,7 @@ impl Solver for Solution {
         Ok(sum)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let hm = input
             .iter()
             .map(|c| (c.id, c.clone()))
@@ -84,9 +85,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d4() {
@@ -98,21 +98,6 @@ Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 ";
 
-        let r = Solution::solve(i, true).unwrap();
-        dbg!(r);
-    }
-
-    #[test]
-    fn d4_2() {
-        let i = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
-";
-
-        let r = Solution::solve(i, false).unwrap();
-        dbg!(r);
+        example_test!(Solution, i, part1 = 13, part2 = 30);
     }
 }
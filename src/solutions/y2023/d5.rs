@@ -47,7 +47,8 @@ pub struct Data {
 
 impl Solver for Solution {
     type Input = Data;
-    type Output = u64;
+    type Output1 = u64;
+    type Output2 = u64;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut data = Data {
@@ -105,13 +106,14 @@ impl Solver for Solution {
         Ok(data)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut loc = u64::MAX;
         for s in input.seeds {
             let mut nextdest = "seed";
             let mut value = s;
+            let mut path = String::new();
             while let Some(m) = input.maps.get(nextdest) {
-                print!("{} ({}), ", nextdest, value);
+                path += &format!("{} ({}), ", nextdest, value);
                 nextdest = &m.dest;
                 for d in m.data.iter() {
                     if value < d.1 {
@@ -122,7 +124,7 @@ impl Solver for Solution {
                     }
                 }
             }
-            println!("{} ({})", nextdest, value);
+            log::trace!("{}{} ({})", path, nextdest, value);
 
             if value < loc {
                 loc = value;
@@ -132,7 +134,7 @@ impl Solver for Solution {
         Ok(loc)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut ranges = vec![];
         for s in input.seeds.chunks(2) {
             let start = s[0];
@@ -143,7 +145,7 @@ impl Solver for Solution {
         let mut nextdest = "seed";
         let mut new_ranges = vec![];
         while let Some(m) = input.maps.get(nextdest) {
-            println!("{}-{}", m.source, m.dest);
+            log::debug!("{}-{}", m.source, m.dest);
             nextdest = &m.dest;
 
             for r in ranges {
@@ -162,9 +164,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d5() {
@@ -202,7 +203,7 @@ temperature-to-humidity map:
 humidity-to-location map:
 60 56 37
 56 93 4";
-        let r = Solution::solve(i, false).unwrap();
-        dbg!(r);
+
+        example_test!(Solution, i, part1 = 35, part2 = 46);
     }
 }
@@ -10,7 +10,8 @@ impl Solution {}
 
 impl Solver for Solution {
     type Input = (Vec<i32>, Vec<i32>);
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut l = input.lines();
@@ -28,7 +29,7 @@ impl Solver for Solution {
         Ok((times, speed))
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         // D = v * (FT - t)
         // v = t * 1
         // D <= t * (FT - t) => t*FT - t2 => t2 - t*FT + D <= 0 => FT +- sqrt(FT2-4FT) / 2
@@ -45,10 +46,10 @@ impl Solver for Solution {
             let maxt = (((t as f32) + ((t * t - 4 * d) as f32).sqrt()) / 2.).floor() as i32;
             let mint = (((t as f32) - ((t * t - 4 * d) as f32).sqrt()) / 2.).ceil() as i32;
 
-            println!("t: {} {}", mint, maxt);
+            log::trace!("t: {} {}", mint, maxt);
             let maxd = maxt * (t - maxt);
             let mind = mint * (t - mint);
-            println!("d: {} {}", mind, maxd);
+            log::trace!("d: {} {}", mind, maxd);
 
             let mut count = maxt - mint + 1;
             if mind == d {
@@ -58,24 +59,24 @@ impl Solver for Solution {
                 count -= 1;
             }
 
-            println!("s: {}", count);
+            log::trace!("s: {}", count);
             res *= count;
         }
 
         Ok(res)
     }
 
-    fn solve_part2(_input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(_input: Self::Input) -> Result<Self::Output2, AocError> {
         let t = 54708275;
         let d = 239114212951253;
 
         let maxt = (((t as f64) + ((t * t - 4 * d) as f64).sqrt()) / 2.).floor() as i64;
         let mint = (((t as f64) - ((t * t - 4 * d) as f64).sqrt()) / 2.).ceil() as i64;
 
-        println!("t: {} {}", mint, maxt);
+        log::debug!("t: {} {}", mint, maxt);
         let maxd = maxt * (t - maxt);
         let mind = mint * (t - mint);
-        println!("d: {} {}", mind, maxd);
+        log::debug!("d: {} {}", mind, maxd);
 
         let mut count = maxt - mint + 1;
         if mind == d {
@@ -90,16 +91,15 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d6() {
         let i = "Time:      7  15   30
 Distance:  9  40  200";
 
-        let res = Solution::solve(i, true).unwrap();
-        dbg!(res);
+        // Part 2 ignores its input and solves the hardcoded race.
+        example_test!(Solution, i, part1 = 288);
     }
 }
@@ -89,7 +89,8 @@ impl Hand {
 
 impl Solver for Solution {
     type Input = Vec<Hand>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let r = input
@@ -109,7 +110,7 @@ impl Solver for Solution {
         Ok(r)
     }
 
-    fn solve_part1(mut input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(mut input: Self::Input) -> Result<Self::Output1, AocError> {
         let card_order = "23456789TJQKA";
 
         let card_rank: HashMap<char, usize> = card_order
@@ -140,13 +141,13 @@ impl Solver for Solution {
         let mut score = 0;
         for (r, i) in input.iter().enumerate() {
             let rank = input.len() - r;
-            println!("{} => {} ({:?})", &i.cs, rank, i.comb());
+            log::trace!("{} => {} ({:?})", &i.cs, rank, i.comb());
             score += i.bid * (rank) as i32;
         }
         Ok(score)
     }
 
-    fn solve_part2(mut input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(mut input: Self::Input) -> Result<Self::Output2, AocError> {
         let card_order = "J23456789TQKA";
 
         let card_rank: HashMap<char, usize> = card_order
@@ -177,7 +178,7 @@ impl Solver for Solution {
         let mut score = 0;
         for (r, i) in input.iter().enumerate() {
             let rank = input.len() - r;
-            println!("{} => {} ({:?})", &i.cs, rank, i.comb2());
+            log::trace!("{} => {} ({:?})", &i.cs, rank, i.comb2());
             score += i.bid * (rank) as i32;
         }
         Ok(score)
@@ -186,9 +187,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d7() {
@@ -197,6 +197,7 @@ T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483";
-        dbg!(Solution::solve(i, true));
+
+        example_test!(Solution, i, part1 = 6440, part2 = 5905);
     }
 }
@@ -40,7 +40,8 @@ pub struct Data {
 
 impl Solver for Solution {
     type Input = Data;
-    type Output = usize;
+    type Output1 = usize;
+    type Output2 = usize;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let mut lines = input.lines();
@@ -70,13 +71,13 @@ impl Solver for Solution {
         })
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let pos = "AAA";
         let count = Solution::solve_start(pos, &input);
         Ok(count)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut starting_pos = vec![];
         for pos in input.paths.keys() {
             if pos.ends_with('A') {
@@ -84,12 +85,12 @@ impl Solver for Solution {
             }
         }
 
-        println!("len: {}", starting_pos.len());
+        log::debug!("len: {}", starting_pos.len());
 
         let mut count = 1;
         for pos in starting_pos {
             let o = Solution::solve_start(&pos, &input);
-            println!("{}", o);
+            log::trace!("{}", o);
             count = lcm(count, o)
         }
 
@@ -99,9 +100,8 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d8() {
@@ -112,6 +112,6 @@ BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)
             ";
 
-        dbg!(Solution::solve(i, true));
+        example_test!(Solution, i, part1 = 6);
     }
 }
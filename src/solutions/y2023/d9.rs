@@ -23,7 +23,8 @@ impl Solution {
 
 impl Solver for Solution {
     type Input = Vec<Vec<i32>>;
-    type Output = i32;
+    type Output1 = i32;
+    type Output2 = i32;
 
     fn parse_input(input: &str) -> Result<Self::Input, AocError> {
         let r = input
@@ -33,7 +34,7 @@ impl Solver for Solution {
         Ok(r)
     }
 
-    fn solve_part1(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part1(input: Self::Input) -> Result<Self::Output1, AocError> {
         let mut ss = 0;
 
         for l in input {
@@ -48,7 +49,7 @@ impl Solver for Solution {
         Ok(ss)
     }
 
-    fn solve_part2(input: Self::Input) -> Result<Self::Output, AocError> {
+    fn solve_part2(input: Self::Input) -> Result<Self::Output2, AocError> {
         let mut ss = 0;
 
         for l in input {
@@ -65,15 +66,14 @@ impl Solver for Solution {
 
 #[cfg(test)]
 mod tests {
-    use crate::solutions::Solver;
-
     use super::Solution;
+    use crate::solutions::test_support::example_test;
 
     #[test]
     fn d9() {
         let i = "10 13 16 21 30 45
 ";
 
-        dbg!(Solution::solve(i, false));
+        example_test!(Solution, i, part1 = 68, part2 = 5);
     }
 }
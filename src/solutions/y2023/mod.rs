@@ -1,25 +1,30 @@
-pub mod d1;
-pub mod d10;
-pub mod d11;
-pub mod d12;
-pub mod d13;
-pub mod d14;
-pub mod d15;
-pub mod d16;
-pub mod d17;
-pub mod d18;
-pub mod d19;
-pub mod d2;
-pub mod d20;
-pub mod d21;
-pub mod d22;
-pub mod d23;
-pub mod d24;
-pub mod d25;
-pub mod d3;
-pub mod d4;
-pub mod d5;
-pub mod d6;
-pub mod d7;
-pub mod d8;
-pub mod d9;
+use crate::solutions::registry::days;
+
+days! {
+    2023;
+    1 => d1, "Trebuchet?!", [1, 2];
+    2 => d2, "Cube Conundrum", [1, 2];
+    3 => d3, "Gear Ratios", [1, 2];
+    4 => d4, "Scratchcards", [1, 2];
+    5 => d5, "If You Give A Seed A Fertilizer", [1, 2];
+    6 => d6, "Wait For It", [1, 2];
+    7 => d7, "Camel Cards", [1, 2];
+    8 => d8, "Haunted Wasteland", [1, 2];
+    9 => d9, "Mirage Maintenance", [1, 2];
+    10 => d10, "Pipe Maze", [1, 2];
+    11 => d11, "Cosmic Expansion", [1, 2];
+    12 => d12, "Hot Springs", [1, 2];
+    13 => d13, "Point of Incidence", [1, 2];
+    14 => d14, "Parabolic Reflector Dish", [1, 2];
+    15 => d15, "Lens Library", [1, 2];
+    16 => d16, "The Floor Will Be Lava", [1, 2];
+    17 => d17, "Clumsy Crucible", [1, 2];
+    18 => d18, "Lavaduct Lagoon", [1, 2];
+    19 => d19, "Aplenty", [1, 2];
+    20 => d20, "Pulse Propagation", [1, 2];
+    21 => d21, "Step Counter", [1, 2];
+    22 => d22, "Sand Slabs", [1, 2];
+    23 => d23, "A Long Walk", [1, 2];
+    24 => d24, "Never Tell Me The Odds", [1, 2];
+    25 => d25, "Snowverload", [1];
+}
@@ -0,0 +1,190 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
+
+use crate::solutions::registry::Day;
+use crate::solutions::{AocError, Solved};
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<Solved, String>,
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Installed once: panics raised inside `solve_catching` are recorded for the
+// current thread, any other panic goes to the previous hook.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(|c| c.get()) {
+                return prev(info);
+            }
+            let msg = if let Some(s) = info.payload().downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = info.payload().downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "unknown panic".to_owned()
+            };
+            let msg = match info.location() {
+                Some(l) => format!("panicked at {}:{}: {}", l.file(), l.line(), msg),
+                None => format!("panicked: {}", msg),
+            };
+            LAST_PANIC.with(|p| *p.borrow_mut() = Some(msg));
+        }));
+    });
+}
+
+// Runs `f`, turning both `AocError`s and panics into an error message so that
+// a broken day does not abort the rest of a batch run.
+fn catching<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, String> {
+    install_hook();
+    CATCHING.with(|c| c.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(false));
+
+    match res {
+        Ok(Ok(v)) => Ok(v),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(LAST_PANIC
+            .with(|p| p.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".to_owned())),
+    }
+}
+
+pub fn solve_catching(day: &Day, input: &str, part1: bool) -> Result<Solved, String> {
+    catching(|| day.solve(input, part1))
+}
+
+// Runs every implemented part of a day from a single parse. Parts left
+// unsolved by a failed parse or a panic get that error.
+pub fn solve_all_catching(day: &Day, input: &str) -> Vec<(u8, Result<Solved, String>)> {
+    let mut results = vec![];
+    let res = catching(|| {
+        day.solve_all(input, &mut |part, r| {
+            results.push((part, r.map_err(|e| e.to_string())))
+        })
+    });
+    if let Err(e) = res {
+        for &part in day.parts[results.len()..].iter() {
+            results.push((part, Err(e.clone())));
+        }
+    }
+    results
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.3}", d.as_secs_f64() * 1000.0)
+}
+
+#[derive(Default)]
+pub struct Summary {
+    rows: Vec<Row>,
+}
+
+impl Summary {
+    pub fn push(&mut self, row: Row) {
+        self.rows.push(row);
+    }
+
+    pub fn failures(&self) -> usize {
+        self.rows.iter().filter(|r| r.result.is_err()).count()
+    }
+
+    pub fn print(&self) {
+        let answers: Vec<String> = self
+            .rows
+            .iter()
+            .map(|r| match &r.result {
+                Ok(s) => s.answer.to_string(),
+                Err(e) => format!("error: {}", e),
+            })
+            .collect();
+        let width = answers
+            .iter()
+            .map(|a| a.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Answer".len());
+
+        println!(
+            "{:<4} {:>3} {:>4}  {:<width$} {:>12} {:>12}",
+            "Year", "Day", "Part", "Answer", "Parse (ms)", "Compute (ms)"
+        );
+        let mut parsing = Duration::ZERO;
+        let mut compute = Duration::ZERO;
+        for (r, answer) in self.rows.iter().zip(answers.iter()) {
+            let (p, c) = match &r.result {
+                Ok(s) => {
+                    parsing += s.parsing();
+                    compute += s.compute();
+                    (ms(s.parsing()), ms(s.compute()))
+                }
+                Err(_) => ("-".to_owned(), "-".to_owned()),
+            };
+            println!(
+                "{:<4} {:>3} {:>4}  {:<width$} {:>12} {:>12}",
+                r.year, r.day, r.part, answer, p, c
+            );
+        }
+        let total = format!("{} parts, {} failed", self.rows.len(), self.failures());
+        println!(
+            "{:<13}  {:<width$} {:>12} {:>12}",
+            "Total",
+            total,
+            ms(parsing),
+            ms(compute)
+        );
+        println!("Total run time: {} ms", ms(parsing + compute));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_all_catching, solve_catching};
+    use crate::solutions::answer::Answer;
+    use crate::solutions::metrics;
+    use crate::solutions::registry::registry;
+
+    #[test]
+    fn catches_panics() {
+        let d17 = registry().get(2023, 17).unwrap();
+        let err = solve_catching(d17, "1x\n11", true).unwrap_err();
+        assert!(err.starts_with("panicked at"), "{}", err);
+        assert!(err.contains("d17.rs"), "{}", err);
+
+        let d1 = registry().get(2021, 1).unwrap();
+        assert!(solve_catching(d1, "1\nx", true).is_err());
+        assert_eq!(
+            solve_catching(d1, "1\n2\n1", true).unwrap().answer,
+            Answer::Integer(1)
+        );
+    }
+
+    #[test]
+    fn all_parts() {
+        let d1 = registry().get(2021, 1).unwrap();
+        let res = solve_all_catching(d1, "1\n2\n1\n4");
+        let answers: Vec<_> = res
+            .iter()
+            .map(|(p, r)| (*p, r.as_ref().unwrap().answer.to_string()))
+            .collect();
+        assert_eq!(answers, vec![(1, "2".into()), (2, "1".into())]);
+        // 2021 day 1 is a `Solver`, whose second part parses the input again
+        let phases = &res[1].1.as_ref().unwrap().metrics.phases;
+        assert!(phases.iter().any(|(p, _)| *p == metrics::PARSE));
+
+        let failed = solve_all_catching(d1, "1\nx");
+        assert_eq!(failed.len(), 2);
+        assert!(failed.iter().all(|(_, r)| r.is_err()));
+    }
+}